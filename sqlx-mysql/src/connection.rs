@@ -1,24 +1,40 @@
 use std::fmt::{self, Debug, Formatter};
 
 use sqlx_core::io::BufStream;
-use sqlx_core::net::Stream as NetStream;
 use sqlx_core::{Close, Connect, Connection, Runtime};
 
 use crate::protocol::Capabilities;
+use crate::transport::MySqlStream;
 use crate::{MySql, MySqlConnectOptions};
 
 mod close;
 mod connect;
 mod ping;
+mod prepare;
+mod query;
+mod session;
+mod statement_cache;
 mod stream;
 
+pub use prepare::MySqlStatement;
+pub use query::MySqlQueryResult;
+
+use session::SessionState;
+use statement_cache::StatementCache;
+
 /// A single connection (also known as a session) to a MySQL database server.
+///
+/// With the `native` feature (on by default), [`Self::connect_async`]/[`Connect::connect`]
+/// dial a host/port or Unix socket directly. Without it -- needed to build for
+/// `wasm32-unknown-unknown`, which has no `std::net` -- use
+/// [`Self::connect_with_stream_async`] over an embedder-supplied [`WasmStream`
+/// ](crate::WasmStream) instead.
 #[allow(clippy::module_name_repetitions)]
 pub struct MySqlConnection<Rt>
 where
     Rt: Runtime,
 {
-    stream: BufStream<Rt, NetStream<Rt>>,
+    stream: BufStream<Rt, MySqlStream<Rt>>,
     connection_id: u32,
 
     // the capability flags are used by the client and server to indicate which
@@ -28,32 +44,62 @@ where
     // the sequence-id is incremented with each packet and may wrap around. It starts at 0 and is
     // reset to 0 when a new command begins in the Command Phase.
     sequence_id: u8,
+
+    // tracks the session state (current schema, system variables) last reported by the
+    // server via `Capabilities::SESSION_TRACK`
+    session: SessionState,
+
+    // whether packets are currently framed using the compressed packet header, per
+    // `Capabilities::COMPRESS`; only flips on once the (always-uncompressed) handshake
+    // response has been sent, even though `capabilities` may already report the bit
+    compression: bool,
+
+    // the compressed-packet sequence id, incremented independently of `sequence_id` and
+    // reset alongside it at the start of each command
+    compression_sequence_id: u8,
+
+    // normal-protocol bytes decompressed from compressed packets but not yet consumed by
+    // `read_packet`/`read_packet_async`
+    compression_read_buffer: Vec<u8>,
+
+    // prepared statements cached by SQL text, bounded by
+    // `MySqlConnectOptions::statement_cache_capacity`
+    statement_cache: StatementCache,
 }
 
 impl<Rt> MySqlConnection<Rt>
 where
     Rt: Runtime,
 {
-    pub(crate) fn new(stream: NetStream<Rt>) -> Self {
+    pub(crate) fn new(stream: MySqlStream<Rt>, statement_cache_capacity: usize) -> Self {
         Self {
             stream: BufStream::with_capacity(stream, 4096, 1024),
             connection_id: 0,
             sequence_id: 0,
+            session: SessionState::default(),
+            compression: false,
+            compression_sequence_id: 0,
+            compression_read_buffer: Vec::new(),
+            statement_cache: StatementCache::new(statement_cache_capacity),
             capabilities: Capabilities::PROTOCOL_41 | Capabilities::LONG_PASSWORD
                 | Capabilities::LONG_FLAG
                 | Capabilities::IGNORE_SPACE
                 | Capabilities::TRANSACTIONS
                 | Capabilities::SECURE_CONNECTION
-                // | Capabilities::MULTI_STATEMENTS
-                // | Capabilities::MULTI_RESULTS
-                // | Capabilities::PS_MULTI_RESULTS
                 | Capabilities::PLUGIN_AUTH
                 | Capabilities::PLUGIN_AUTH_LENENC_DATA
                 // | Capabilities::CAN_HANDLE_EXPIRED_PASSWORDS
-                // | Capabilities::SESSION_TRACK
+                | Capabilities::SESSION_TRACK
                 | Capabilities::DEPRECATE_EOF,
         }
     }
+
+    // resets both packet sequence counters to 0, as required at the start of every command
+    // in the Command Phase
+    pub(crate) fn begin_command(&mut self) {
+        self.sequence_id = 0;
+        self.compression_sequence_id = 0;
+    }
 }
 
 impl<Rt> Debug for MySqlConnection<Rt>
@@ -80,6 +126,10 @@ where
     }
 }
 
+// connecting straight from a URL means dialing a host/port (or a Unix socket) ourselves,
+// which is exactly what the `native` feature gates; without it, open the transport yourself
+// and hand it to `MySqlConnection::connect_with_stream_async` instead
+#[cfg(feature = "native")]
 impl<Rt: Runtime> Connect<Rt> for MySqlConnection<Rt> {
     type Options = MySqlConnectOptions<Rt>;
 
@@ -119,6 +169,8 @@ mod blocking {
         }
     }
 
+    // see the note on the async `Connect` impl above: dialing from a URL requires `native`
+    #[cfg(feature = "native")]
     impl<Rt: Runtime> Connect<Rt> for MySqlConnection<Rt> {
         #[inline]
         fn connect(url: &str) -> sqlx_core::Result<Self>
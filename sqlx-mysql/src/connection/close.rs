@@ -0,0 +1,43 @@
+use sqlx_core::Runtime;
+
+use super::MySqlConnection;
+
+// <https://dev.mysql.com/doc/dev/mysql-server/latest/page_protocol_com_quit.html>
+const COM_QUIT: u8 = 0x01;
+
+impl<Rt> MySqlConnection<Rt>
+where
+    Rt: Runtime,
+{
+    #[cfg(feature = "async")]
+    pub(super) async fn close_async(mut self) -> sqlx_core::Result<()>
+    where
+        Rt: sqlx_core::Async,
+    {
+        for statement in self.statement_cache.drain() {
+            self.close_statement_async(statement.statement_id).await?;
+        }
+
+        self.begin_command();
+        self.write_packet_async(&[COM_QUIT]).await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<Rt> MySqlConnection<Rt>
+where
+    Rt: sqlx_core::blocking::Runtime,
+{
+    pub(super) fn close(mut self) -> sqlx_core::Result<()> {
+        for statement in self.statement_cache.drain() {
+            self.close_statement(statement.statement_id)?;
+        }
+
+        self.begin_command();
+        self.write_packet(&[COM_QUIT])?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,339 @@
+use sqlx_core::io::BufStream;
+use sqlx_core::Runtime;
+
+use crate::protocol::auth::native_password_response;
+use crate::protocol::{Capabilities, Decode, ErrPacket, Handshake, OkPacket};
+use crate::transport::MySqlStream;
+use crate::{MySqlConnectOptions, MySqlConnection};
+
+use super::query::protocol_error;
+
+#[cfg(feature = "native")]
+use sqlx_core::net::{tls, Stream as NetStream};
+#[cfg(feature = "native")]
+use sqlx_core::Error;
+#[cfg(feature = "native")]
+use crate::options::MySqlSslMode;
+
+impl<Rt> MySqlConnection<Rt>
+where
+    Rt: Runtime,
+{
+    #[cfg(all(feature = "async", feature = "native"))]
+    pub(crate) async fn connect_async(options: &MySqlConnectOptions<Rt>) -> sqlx_core::Result<Self>
+    where
+        Rt: sqlx_core::Async,
+    {
+        let stream = if let Some(socket) = options.get_socket() {
+            NetStream::connect_unix_async(socket).await?
+        } else {
+            NetStream::connect_tcp_async(options.get_host(), options.get_port()).await?
+        };
+
+        let mut conn = Self::new(stream, options.get_statement_cache_capacity());
+        conn.request_capabilities(options);
+
+        let payload = conn.read_packet_async().await?;
+        let handshake = Handshake::decode(&payload)?;
+
+        conn.connection_id = handshake.connection_id;
+        conn.capabilities &= handshake.capabilities;
+
+        conn.upgrade_if_requested_async(options, &handshake).await?;
+
+        let auth_response = options
+            .get_password()
+            .map(|password| native_password_response(password, &handshake.auth_plugin_data));
+
+        let response = conn.encode_handshake_response(options, auth_response.as_deref());
+        conn.write_packet_async(&response).await?;
+
+        // the handshake response is the last packet exchanged in plain (uncompressed)
+        // framing; everything from here on, starting with the reply to it, is compressed
+        conn.compression = conn.capabilities.contains(Capabilities::COMPRESS);
+
+        conn.read_auth_result_async().await?;
+
+        Ok(conn)
+    }
+
+    /// Establishes a connection over an already-open, embedder-supplied transport instead of
+    /// dialing a host/port or Unix socket directly -- the path available without the `native`
+    /// feature, since there is then no `std::net` for this crate to dial with itself.
+    ///
+    /// The channel is assumed to already be connected to the server and, if required, already
+    /// secured: `options.get_ssl_mode()` is not consulted here, as there is no raw socket for
+    /// this crate to upgrade to TLS on its own.
+    #[cfg(all(feature = "async", not(feature = "native")))]
+    pub async fn connect_with_stream_async(
+        options: &MySqlConnectOptions<Rt>,
+        stream: crate::WasmStream,
+    ) -> sqlx_core::Result<Self>
+    where
+        Rt: sqlx_core::Async,
+    {
+        let mut conn = Self::new(MySqlStream::new(stream), options.get_statement_cache_capacity());
+        conn.request_capabilities(options);
+
+        let payload = conn.read_packet_async().await?;
+        let handshake = Handshake::decode(&payload)?;
+
+        conn.connection_id = handshake.connection_id;
+        conn.capabilities &= handshake.capabilities;
+
+        let auth_response = options
+            .get_password()
+            .map(|password| native_password_response(password, &handshake.auth_plugin_data));
+
+        let response = conn.encode_handshake_response(options, auth_response.as_deref());
+        conn.write_packet_async(&response).await?;
+
+        // the handshake response is the last packet exchanged in plain (uncompressed)
+        // framing; everything from here on, starting with the reply to it, is compressed
+        conn.compression = conn.capabilities.contains(Capabilities::COMPRESS);
+
+        conn.read_auth_result_async().await?;
+
+        Ok(conn)
+    }
+
+    #[cfg(all(feature = "async", feature = "native"))]
+    async fn upgrade_if_requested_async(
+        &mut self,
+        options: &MySqlConnectOptions<Rt>,
+        handshake: &Handshake,
+    ) -> sqlx_core::Result<()>
+    where
+        Rt: sqlx_core::Async,
+    {
+        let Some(tls_options) = self.plan_upgrade(options, handshake)? else {
+            return Ok(());
+        };
+
+        // the SSL request is the 32-byte prefix of the handshake response: the client
+        // capability flags (with `SSL` set), max packet size, and charset byte, with no
+        // username/auth payload -- the server starts the TLS handshake as soon as it reads
+        // this much
+        self.write_packet_async(&ssl_request(self.capabilities)).await?;
+
+        let stream = self.stream.take_inner();
+        let stream = tls::upgrade_async(stream, tls_options).await?;
+
+        self.stream = BufStream::with_capacity(stream, 4096, 1024);
+
+        Ok(())
+    }
+
+    #[cfg(all(feature = "blocking", feature = "native"))]
+    pub(super) fn connect(options: &MySqlConnectOptions<Rt>) -> sqlx_core::Result<Self>
+    where
+        Rt: sqlx_core::blocking::Runtime,
+    {
+        let stream = if let Some(socket) = options.get_socket() {
+            NetStream::connect_unix(socket)?
+        } else {
+            NetStream::connect_tcp(options.get_host(), options.get_port())?
+        };
+
+        let mut conn = Self::new(stream, options.get_statement_cache_capacity());
+        conn.request_capabilities(options);
+
+        let payload = conn.read_packet()?;
+        let handshake = Handshake::decode(&payload)?;
+
+        conn.connection_id = handshake.connection_id;
+        conn.capabilities &= handshake.capabilities;
+
+        conn.upgrade_if_requested(options, &handshake)?;
+
+        let auth_response = options
+            .get_password()
+            .map(|password| native_password_response(password, &handshake.auth_plugin_data));
+
+        let response = conn.encode_handshake_response(options, auth_response.as_deref());
+        conn.write_packet(&response)?;
+
+        // the handshake response is the last packet exchanged in plain (uncompressed)
+        // framing; everything from here on, starting with the reply to it, is compressed
+        conn.compression = conn.capabilities.contains(Capabilities::COMPRESS);
+
+        conn.read_auth_result()?;
+
+        Ok(conn)
+    }
+
+    #[cfg(all(feature = "blocking", feature = "native"))]
+    fn upgrade_if_requested(
+        &mut self,
+        options: &MySqlConnectOptions<Rt>,
+        handshake: &Handshake,
+    ) -> sqlx_core::Result<()>
+    where
+        Rt: sqlx_core::blocking::Runtime,
+    {
+        let Some(tls_options) = self.plan_upgrade(options, handshake)? else {
+            return Ok(());
+        };
+
+        self.write_packet(&ssl_request(self.capabilities))?;
+
+        let stream = self.stream.take_inner();
+        let stream = tls::upgrade(stream, tls_options)?;
+
+        self.stream = BufStream::with_capacity(stream, 4096, 1024);
+
+        Ok(())
+    }
+
+    // reads the server's reply to the handshake response and fails the connection attempt if
+    // it's an `ERR_Packet` (wrong password, missing privileges, ...) instead of returning a
+    // connection the caller would believe is authenticated; the accompanying `OK` packet also
+    // carries the initial session state (e.g. the schema selected via `CONNECT_WITH_DB`), so
+    // it's run through `record_session_state_changes` like any other
+    #[cfg(feature = "async")]
+    async fn read_auth_result_async(&mut self) -> sqlx_core::Result<()>
+    where
+        Rt: sqlx_core::Async,
+    {
+        let payload = self.read_packet_async().await?;
+        self.handle_auth_result(&payload)
+    }
+
+    #[cfg(feature = "blocking")]
+    fn read_auth_result(&mut self) -> sqlx_core::Result<()>
+    where
+        Rt: sqlx_core::blocking::Runtime,
+    {
+        let payload = self.read_packet()?;
+        self.handle_auth_result(&payload)
+    }
+
+    fn handle_auth_result(&mut self, payload: &[u8]) -> sqlx_core::Result<()> {
+        if payload.first() == Some(&0xff) {
+            return Err(protocol_error(ErrPacket::decode_with(payload, self.capabilities)?));
+        }
+
+        let ok = OkPacket::decode_with(payload, self.capabilities)?;
+        self.record_session_state_changes(&ok)
+    }
+
+    // opts into capabilities that are off by default and only make sense once the caller has
+    // deliberately asked for them via `options`; narrowed down further once the handshake
+    // tells us what the server actually supports
+    fn request_capabilities(&mut self, options: &MySqlConnectOptions<Rt>) {
+        if options.get_multi_statements() {
+            self.capabilities |= Capabilities::MULTI_STATEMENTS
+                | Capabilities::MULTI_RESULTS
+                | Capabilities::PS_MULTI_RESULTS;
+        }
+
+        if options.get_compression() {
+            self.capabilities |= Capabilities::COMPRESS;
+        }
+
+        if options.get_database().is_some() {
+            self.capabilities |= Capabilities::CONNECT_WITH_DB;
+        }
+    }
+
+    // decides whether a TLS upgrade should happen for this `ssl-mode`, given what the server
+    // advertised in its handshake, and flips on `Capabilities::SSL` if so; returns `None` when
+    // the connection should stay (or fall back to being) plaintext
+    #[cfg(feature = "native")]
+    fn plan_upgrade<'o>(
+        &mut self,
+        options: &'o MySqlConnectOptions<Rt>,
+        handshake: &Handshake,
+    ) -> sqlx_core::Result<Option<tls::Options<'o>>> {
+        let ssl_mode = options.get_ssl_mode();
+
+        if ssl_mode == MySqlSslMode::Disabled {
+            return Ok(None);
+        }
+
+        if !handshake.capabilities.contains(Capabilities::SSL) {
+            return if ssl_mode == MySqlSslMode::Preferred {
+                Ok(None)
+            } else {
+                Err(Error::protocol(
+                    "TLS was required by `ssl-mode` but the server does not support it",
+                ))
+            };
+        }
+
+        self.capabilities |= Capabilities::SSL;
+
+        Ok(Some(tls::Options {
+            hostname: options.get_host(),
+            accept_invalid_certs: ssl_mode <= MySqlSslMode::Required,
+            accept_invalid_hostnames: ssl_mode < MySqlSslMode::VerifyIdentity,
+            root_cert_path: options.get_ssl_ca(),
+            client_cert_path: options.get_ssl_cert(),
+            client_key_path: options.get_ssl_key(),
+        }))
+    }
+
+    fn encode_handshake_response(
+        &self,
+        options: &MySqlConnectOptions<Rt>,
+        auth_response: Option<&[u8]>,
+    ) -> Vec<u8> {
+        let mut buf = ssl_request(self.capabilities);
+
+        buf.extend_from_slice(options.get_username().unwrap_or_default().as_bytes());
+        buf.push(0);
+
+        if self.capabilities.contains(Capabilities::PLUGIN_AUTH_LENENC_DATA) {
+            let auth_response = auth_response.unwrap_or_default();
+            buf.push(auth_response.len() as u8);
+            buf.extend_from_slice(auth_response);
+        } else {
+            buf.push(0);
+        }
+
+        if self.capabilities.contains(Capabilities::CONNECT_WITH_DB) {
+            if let Some(database) = options.get_database() {
+                buf.extend_from_slice(database.as_bytes());
+                buf.push(0);
+            }
+        }
+
+        if self.capabilities.contains(Capabilities::PLUGIN_AUTH) {
+            buf.extend_from_slice(b"mysql_native_password");
+            buf.push(0);
+        }
+
+        buf
+    }
+}
+
+// the common 32-byte prefix shared by the SSL-request packet and the handshake response:
+// client capability flags, max packet size, and the charset to use for the connection
+fn ssl_request(capabilities: Capabilities) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(32);
+
+    buf.extend_from_slice(&(capabilities.bits() as u32).to_le_bytes());
+    buf.extend_from_slice(&(1024 * 1024 * 1024_u32).to_le_bytes());
+
+    // utf8mb4_general_ci
+    buf.push(45);
+
+    buf.extend_from_slice(&[0_u8; 23]);
+
+    buf
+}
+
+#[cfg(all(feature = "async", feature = "native"))]
+impl<Rt> sqlx_core::ConnectOptions<Rt> for MySqlConnectOptions<Rt>
+where
+    Rt: Runtime,
+{
+    type Connection = MySqlConnection<Rt>;
+
+    fn connect(&self) -> futures_util::future::BoxFuture<'_, sqlx_core::Result<Self::Connection>>
+    where
+        Rt: sqlx_core::Async,
+    {
+        Box::pin(MySqlConnection::connect_async(self))
+    }
+}
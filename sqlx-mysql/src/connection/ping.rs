@@ -0,0 +1,37 @@
+use sqlx_core::Runtime;
+
+use super::MySqlConnection;
+
+// <https://dev.mysql.com/doc/dev/mysql-server/latest/page_protocol_com_ping.html>
+const COM_PING: u8 = 0x0e;
+
+impl<Rt> MySqlConnection<Rt>
+where
+    Rt: Runtime,
+{
+    #[cfg(feature = "async")]
+    pub(super) async fn ping_async(&mut self) -> sqlx_core::Result<()>
+    where
+        Rt: sqlx_core::Async,
+    {
+        self.begin_command();
+        self.write_packet_async(&[COM_PING]).await?;
+        self.read_packet_async().await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<Rt> MySqlConnection<Rt>
+where
+    Rt: sqlx_core::blocking::Runtime,
+{
+    pub(super) fn ping(&mut self) -> sqlx_core::Result<()> {
+        self.begin_command();
+        self.write_packet(&[COM_PING])?;
+        self.read_packet()?;
+
+        Ok(())
+    }
+}
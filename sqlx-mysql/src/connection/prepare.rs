@@ -0,0 +1,204 @@
+use sqlx_core::Runtime;
+
+use crate::protocol::{
+    Capabilities, ColumnDefinition, Command, Decode, Encode, ErrPacket, StmtPrepareOkPacket,
+};
+
+use super::query::protocol_error;
+use super::statement_cache::CachedStatement;
+use super::MySqlConnection;
+
+/// A SQL statement prepared on the server, either just now or by a previous call with the same
+/// text, as returned by [`MySqlConnection::prepare_async`].
+///
+/// Held by [`MySqlConnection`] itself (in its [`statement_cache_capacity`
+/// ](crate::MySqlConnectOptions::statement_cache_capacity)-bounded cache, keyed by SQL text) for
+/// as long as it isn't evicted, so that repeating the same SQL reuses the server-assigned
+/// statement instead of re-preparing it.
+#[derive(Debug, Clone)]
+pub struct MySqlStatement {
+    pub(crate) statement_id: u32,
+    params: usize,
+    columns: usize,
+}
+
+impl MySqlStatement {
+    /// The number of `?` placeholders this statement expects to be bound.
+    pub fn param_count(&self) -> usize {
+        self.params
+    }
+
+    /// The number of columns the result set of executing this statement will have, or `0` if
+    /// it does not return one (e.g. an `INSERT`, `UPDATE`, or DDL statement).
+    pub fn column_count(&self) -> usize {
+        self.columns
+    }
+}
+
+impl From<&CachedStatement> for MySqlStatement {
+    fn from(cached: &CachedStatement) -> Self {
+        Self {
+            statement_id: cached.statement_id,
+            params: cached.params.len(),
+            columns: cached.columns.len(),
+        }
+    }
+}
+
+impl<Rt> MySqlConnection<Rt>
+where
+    Rt: Runtime,
+{
+    /// Prepares `sql` on the server, or returns the already-prepared statement if an identical
+    /// call was cached -- up to
+    /// [`MySqlConnectOptions::statement_cache_capacity`](crate::MySqlConnectOptions::statement_cache_capacity)
+    /// statements are kept around at once, least-recently-used first.
+    ///
+    /// Evicting a cached statement to make room for this one sends `COM_STMT_CLOSE` for the one
+    /// dropped, so the server doesn't keep accumulating statements this driver has forgotten
+    /// about.
+    #[cfg(feature = "async")]
+    pub async fn prepare_async(&mut self, sql: &str) -> sqlx_core::Result<MySqlStatement>
+    where
+        Rt: sqlx_core::Async,
+    {
+        if let Some(cached) = self.statement_cache.get(sql) {
+            return Ok(cached.into());
+        }
+
+        let statement = self.prepare_uncached_async(sql).await?;
+        let result = MySqlStatement::from(&statement);
+
+        if let Some(evicted) = self.statement_cache.insert(sql.to_string(), statement) {
+            self.close_statement_async(evicted.statement_id).await?;
+        }
+
+        Ok(result)
+    }
+
+    #[cfg(feature = "async")]
+    async fn prepare_uncached_async(&mut self, sql: &str) -> sqlx_core::Result<CachedStatement>
+    where
+        Rt: sqlx_core::Async,
+    {
+        self.begin_command();
+
+        let mut buf = Vec::new();
+        Command::Prepare(sql.to_string()).encode(&mut buf, self.capabilities);
+        self.write_packet_async(&buf).await?;
+
+        let payload = self.read_packet_async().await?;
+        if payload.first() == Some(&0xff) {
+            return Err(protocol_error(ErrPacket::decode_with(&payload, self.capabilities)?));
+        }
+
+        let ok = StmtPrepareOkPacket::decode_with(&payload, self.capabilities)?;
+
+        let mut params = Vec::with_capacity(ok.num_params as usize);
+        for _ in 0..ok.num_params {
+            let payload = self.read_packet_async().await?;
+            params.push(ColumnDefinition::decode_with(&payload, self.capabilities)?);
+        }
+        if ok.num_params > 0 && !self.capabilities.contains(Capabilities::DEPRECATE_EOF) {
+            self.read_packet_async().await?;
+        }
+
+        let mut columns = Vec::with_capacity(ok.num_columns as usize);
+        for _ in 0..ok.num_columns {
+            let payload = self.read_packet_async().await?;
+            columns.push(ColumnDefinition::decode_with(&payload, self.capabilities)?);
+        }
+        if ok.num_columns > 0 && !self.capabilities.contains(Capabilities::DEPRECATE_EOF) {
+            self.read_packet_async().await?;
+        }
+
+        Ok(CachedStatement { statement_id: ok.statement_id, params, columns })
+    }
+
+    #[cfg(feature = "async")]
+    pub(super) async fn close_statement_async(&mut self, statement_id: u32) -> sqlx_core::Result<()>
+    where
+        Rt: sqlx_core::Async,
+    {
+        self.begin_command();
+
+        let mut buf = Vec::new();
+        Command::StmtClose(statement_id).encode(&mut buf, self.capabilities);
+        self.write_packet_async(&buf).await?;
+
+        Ok(())
+    }
+
+    /// Blocking counterpart to [`Self::prepare_async`].
+    #[cfg(feature = "blocking")]
+    pub fn prepare(&mut self, sql: &str) -> sqlx_core::Result<MySqlStatement>
+    where
+        Rt: sqlx_core::blocking::Runtime,
+    {
+        if let Some(cached) = self.statement_cache.get(sql) {
+            return Ok(cached.into());
+        }
+
+        let statement = self.prepare_uncached(sql)?;
+        let result = MySqlStatement::from(&statement);
+
+        if let Some(evicted) = self.statement_cache.insert(sql.to_string(), statement) {
+            self.close_statement(evicted.statement_id)?;
+        }
+
+        Ok(result)
+    }
+
+    #[cfg(feature = "blocking")]
+    fn prepare_uncached(&mut self, sql: &str) -> sqlx_core::Result<CachedStatement>
+    where
+        Rt: sqlx_core::blocking::Runtime,
+    {
+        self.begin_command();
+
+        let mut buf = Vec::new();
+        Command::Prepare(sql.to_string()).encode(&mut buf, self.capabilities);
+        self.write_packet(&buf)?;
+
+        let payload = self.read_packet()?;
+        if payload.first() == Some(&0xff) {
+            return Err(protocol_error(ErrPacket::decode_with(&payload, self.capabilities)?));
+        }
+
+        let ok = StmtPrepareOkPacket::decode_with(&payload, self.capabilities)?;
+
+        let mut params = Vec::with_capacity(ok.num_params as usize);
+        for _ in 0..ok.num_params {
+            let payload = self.read_packet()?;
+            params.push(ColumnDefinition::decode_with(&payload, self.capabilities)?);
+        }
+        if ok.num_params > 0 && !self.capabilities.contains(Capabilities::DEPRECATE_EOF) {
+            self.read_packet()?;
+        }
+
+        let mut columns = Vec::with_capacity(ok.num_columns as usize);
+        for _ in 0..ok.num_columns {
+            let payload = self.read_packet()?;
+            columns.push(ColumnDefinition::decode_with(&payload, self.capabilities)?);
+        }
+        if ok.num_columns > 0 && !self.capabilities.contains(Capabilities::DEPRECATE_EOF) {
+            self.read_packet()?;
+        }
+
+        Ok(CachedStatement { statement_id: ok.statement_id, params, columns })
+    }
+
+    #[cfg(feature = "blocking")]
+    pub(super) fn close_statement(&mut self, statement_id: u32) -> sqlx_core::Result<()>
+    where
+        Rt: sqlx_core::blocking::Runtime,
+    {
+        self.begin_command();
+
+        let mut buf = Vec::new();
+        Command::StmtClose(statement_id).encode(&mut buf, self.capabilities);
+        self.write_packet(&buf)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,249 @@
+use sqlx_core::{Error, Runtime};
+
+use crate::protocol::{
+    Capabilities, ColumnDefinition, Command, Decode, Encode, EofPacket, ErrPacket, OkPacket,
+    StatusFlags, TextRow,
+};
+
+use super::MySqlConnection;
+
+/// The result of a single SQL statement, as produced by one step of a (possibly
+/// multi-statement) [`MySqlConnection::query`] call.
+#[derive(Debug)]
+pub enum MySqlQueryResult {
+    /// The statement did not return a result set (e.g. an `INSERT`, `UPDATE`, or DDL
+    /// statement).
+    Done { rows_affected: u64, last_insert_id: u64 },
+
+    /// The statement returned a result set.
+    Rows { columns: Vec<ColumnDefinition>, rows: Vec<TextRow> },
+}
+
+impl<Rt> MySqlConnection<Rt>
+where
+    Rt: Runtime,
+{
+    /// Runs one or more semicolon-separated SQL statements, returning one
+    /// [`MySqlQueryResult`] per statement.
+    ///
+    /// More than one statement may only be submitted at once if
+    /// [`MySqlConnectOptions::multi_statements`](crate::MySqlConnectOptions::multi_statements)
+    /// was enabled when this connection was opened; otherwise the server rejects `sql`
+    /// containing more than one statement.
+    #[cfg(feature = "async")]
+    pub async fn query_async(&mut self, sql: &str) -> sqlx_core::Result<Vec<MySqlQueryResult>>
+    where
+        Rt: sqlx_core::Async,
+    {
+        self.begin_command();
+
+        let mut buf = Vec::new();
+        Command::Query(sql.to_string()).encode(&mut buf, self.capabilities);
+        self.write_packet_async(&buf).await?;
+
+        let mut results = Vec::new();
+
+        loop {
+            let (result, status) = self.read_query_result_async().await?;
+            results.push(result);
+
+            if !self.has_more_results(status) {
+                break;
+            }
+        }
+
+        Ok(results)
+    }
+
+    #[cfg(feature = "async")]
+    async fn read_query_result_async(
+        &mut self,
+    ) -> sqlx_core::Result<(MySqlQueryResult, StatusFlags)>
+    where
+        Rt: sqlx_core::Async,
+    {
+        let payload = self.read_packet_async().await?;
+
+        match payload.first() {
+            Some(0xff) => Err(protocol_error(ErrPacket::decode_with(&payload, self.capabilities)?)),
+
+            _ if is_ok_packet(&payload) => {
+                let ok = OkPacket::decode_with(&payload, self.capabilities)?;
+                self.record_session_state_changes(&ok)?;
+                let status = ok.status;
+
+                Ok((
+                    MySqlQueryResult::Done {
+                        rows_affected: ok.affected_rows,
+                        last_insert_id: ok.last_insert_id,
+                    },
+                    status,
+                ))
+            }
+
+            _ => {
+                let column_count = column_count(&payload)?;
+                let mut columns = Vec::with_capacity(column_count);
+
+                for _ in 0..column_count {
+                    let payload = self.read_packet_async().await?;
+                    columns.push(ColumnDefinition::decode_with(&payload, self.capabilities)?);
+                }
+
+                if !self.capabilities.contains(Capabilities::DEPRECATE_EOF) {
+                    self.read_packet_async().await?;
+                }
+
+                let mut rows = Vec::new();
+
+                loop {
+                    let payload = self.read_packet_async().await?;
+
+                    if is_row_terminator(&payload) {
+                        let status = self.row_terminator_status(&payload)?;
+                        return Ok((MySqlQueryResult::Rows { columns, rows }, status));
+                    }
+
+                    rows.push(TextRow::decode(&payload, column_count)?);
+                }
+            }
+        }
+    }
+
+    /// Runs one or more semicolon-separated SQL statements, returning one
+    /// [`MySqlQueryResult`] per statement.
+    ///
+    /// More than one statement may only be submitted at once if
+    /// [`MySqlConnectOptions::multi_statements`](crate::MySqlConnectOptions::multi_statements)
+    /// was enabled when this connection was opened; otherwise the server rejects `sql`
+    /// containing more than one statement.
+    #[cfg(feature = "blocking")]
+    pub fn query(&mut self, sql: &str) -> sqlx_core::Result<Vec<MySqlQueryResult>>
+    where
+        Rt: sqlx_core::blocking::Runtime,
+    {
+        self.begin_command();
+
+        let mut buf = Vec::new();
+        Command::Query(sql.to_string()).encode(&mut buf, self.capabilities);
+        self.write_packet(&buf)?;
+
+        let mut results = Vec::new();
+
+        loop {
+            let (result, status) = self.read_query_result()?;
+            results.push(result);
+
+            if !self.has_more_results(status) {
+                break;
+            }
+        }
+
+        Ok(results)
+    }
+
+    #[cfg(feature = "blocking")]
+    fn read_query_result(&mut self) -> sqlx_core::Result<(MySqlQueryResult, StatusFlags)>
+    where
+        Rt: sqlx_core::blocking::Runtime,
+    {
+        let payload = self.read_packet()?;
+
+        match payload.first() {
+            Some(0xff) => Err(protocol_error(ErrPacket::decode_with(&payload, self.capabilities)?)),
+
+            _ if is_ok_packet(&payload) => {
+                let ok = OkPacket::decode_with(&payload, self.capabilities)?;
+                self.record_session_state_changes(&ok)?;
+                let status = ok.status;
+
+                Ok((
+                    MySqlQueryResult::Done {
+                        rows_affected: ok.affected_rows,
+                        last_insert_id: ok.last_insert_id,
+                    },
+                    status,
+                ))
+            }
+
+            _ => {
+                let column_count = column_count(&payload)?;
+                let mut columns = Vec::with_capacity(column_count);
+
+                for _ in 0..column_count {
+                    let payload = self.read_packet()?;
+                    columns.push(ColumnDefinition::decode_with(&payload, self.capabilities)?);
+                }
+
+                if !self.capabilities.contains(Capabilities::DEPRECATE_EOF) {
+                    self.read_packet()?;
+                }
+
+                let mut rows = Vec::new();
+
+                loop {
+                    let payload = self.read_packet()?;
+
+                    if is_row_terminator(&payload) {
+                        let status = self.row_terminator_status(&payload)?;
+                        return Ok((MySqlQueryResult::Rows { columns, rows }, status));
+                    }
+
+                    rows.push(TextRow::decode(&payload, column_count)?);
+                }
+            }
+        }
+    }
+
+    // whether the server has indicated that another result set follows the one just read;
+    // only meaningful (and only ever set by the server) when `CLIENT_MULTI_RESULTS` was
+    // negotiated, i.e. `MySqlConnectOptions::multi_statements` was enabled
+    fn has_more_results(&self, status: StatusFlags) -> bool {
+        self.capabilities.contains(Capabilities::MULTI_RESULTS)
+            && status.contains(StatusFlags::MORE_RESULTS_EXISTS)
+    }
+
+    // decodes the packet ending a result set's rows: an OK packet when `DEPRECATE_EOF` was
+    // negotiated, an `EOF_Packet` otherwise; returns its status flags
+    fn row_terminator_status(&mut self, payload: &[u8]) -> sqlx_core::Result<StatusFlags> {
+        if !self.capabilities.contains(Capabilities::DEPRECATE_EOF) {
+            return Ok(EofPacket::decode(payload)?.status);
+        }
+
+        let ok = OkPacket::decode_with(payload, self.capabilities)?;
+        self.record_session_state_changes(&ok)?;
+
+        Ok(ok.status)
+    }
+}
+
+// the column-count packet that precedes a text result set is a single length-encoded
+// integer spanning the whole payload
+fn column_count(payload: &[u8]) -> sqlx_core::Result<usize> {
+    match payload.first() {
+        Some(0xfb) => Err(Error::protocol("`LOAD DATA LOCAL INFILE` is not supported")),
+        _ => {
+            let mut buf = crate::protocol::Buf::new(payload);
+            Ok(buf.take_int_lenenc()? as usize)
+        }
+    }
+}
+
+// whether `payload` is a direct OK packet response to a command that never sent a preceding
+// column-count packet, i.e. the statement returned no result set
+fn is_ok_packet(payload: &[u8]) -> bool {
+    matches!(payload.first(), Some(0x00))
+}
+
+// the server signals the end of a result set's rows with an `EOF_Packet` or, when
+// `DEPRECATE_EOF` was negotiated, an OK packet that uses the EOF header byte (0xfe) here to stay
+// distinguishable from a row whose first column happens to be an empty string (and so also
+// starts with a single 0x00 byte); a row that starts with 0xfe begins with the 9-byte form of a
+// length-encoded integer, so only 0xfe packets shorter than that are taken as terminators
+fn is_row_terminator(payload: &[u8]) -> bool {
+    matches!(payload.first(), Some(0xfe)) && payload.len() < 9
+}
+
+pub(super) fn protocol_error(err: ErrPacket) -> Error {
+    Error::protocol(format!("{} ({}): {}", err.code, err.sql_state, err.message))
+}
@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+
+use sqlx_core::Runtime;
+
+use crate::protocol::{decode_session_state_changes, OkPacket, SessionStateChange, StatusFlags};
+
+use super::MySqlConnection;
+
+/// The session state (current schema and system variables) last reported by the server via
+/// [`Capabilities::SESSION_TRACK`](crate::protocol::Capabilities::SESSION_TRACK).
+///
+/// Kept up to date from the session-state-change payload that trails an [`OkPacket`] whenever
+/// the server flips [`StatusFlags::SESSION_STATE_CHANGED`], so it reflects changes made by
+/// statements such as `USE db` or `SET time_zone = ...` without a round trip to re-query them.
+#[derive(Debug, Default)]
+pub(crate) struct SessionState {
+    database: Option<String>,
+    variables: HashMap<String, String>,
+}
+
+impl SessionState {
+    fn apply(&mut self, changes: Vec<SessionStateChange>) {
+        for change in changes {
+            match change {
+                SessionStateChange::Schema(schema) => self.database = Some(schema),
+                SessionStateChange::SystemVariable { name, value } => {
+                    self.variables.insert(name, value);
+                }
+                SessionStateChange::StateChange(_)
+                | SessionStateChange::Gtids(_)
+                | SessionStateChange::Other { .. } => {}
+            }
+        }
+    }
+}
+
+impl<Rt> MySqlConnection<Rt>
+where
+    Rt: Runtime,
+{
+    /// The default schema (database) most recently reported by the server, e.g. after a
+    /// `USE db` statement.
+    ///
+    /// `None` until the server has reported a schema change at least once; requires
+    /// [`Capabilities::SESSION_TRACK`](crate::protocol::Capabilities::SESSION_TRACK), which is
+    /// negotiated automatically when the server supports it.
+    pub fn current_database(&self) -> Option<&str> {
+        self.session.database.as_deref()
+    }
+
+    /// The value of a session system variable (e.g. `character_set_client`, `time_zone`) as
+    /// most recently reported by the server.
+    pub fn session_variable(&self, name: &str) -> Option<&str> {
+        self.session.variables.get(name).map(String::as_str)
+    }
+
+    /// The connection (client) character set most recently reported by the server.
+    pub fn current_charset(&self) -> Option<&str> {
+        self.session_variable("character_set_client")
+    }
+
+    /// The session time zone most recently reported by the server.
+    pub fn current_timezone(&self) -> Option<&str> {
+        self.session_variable("time_zone")
+    }
+
+    // applies the session-state-change payload trailing an `OK` packet, if the server set
+    // `SESSION_STATE_CHANGED` on it; a no-op otherwise, and always a no-op unless
+    // `Capabilities::SESSION_TRACK` was negotiated, since the server never sends the payload
+    // (or sets the flag) without it
+    pub(crate) fn record_session_state_changes(&mut self, ok: &OkPacket) -> sqlx_core::Result<()> {
+        if !ok.status.contains(StatusFlags::SESSION_STATE_CHANGED) {
+            return Ok(());
+        }
+
+        let changes = decode_session_state_changes(&ok.session_state_changes)?;
+        self.session.apply(changes);
+
+        Ok(())
+    }
+}
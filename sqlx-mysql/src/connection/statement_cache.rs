@@ -0,0 +1,149 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::protocol::ColumnDefinition;
+
+/// A prepared statement's server-assigned id and the parameter/column metadata the server
+/// returned when it was prepared, as cached by [`StatementCache`].
+#[derive(Debug, Clone)]
+pub(crate) struct CachedStatement {
+    pub(crate) statement_id: u32,
+    pub(crate) params: Vec<ColumnDefinition>,
+    pub(crate) columns: Vec<ColumnDefinition>,
+}
+
+/// An LRU-bounded cache of server-side prepared statements, keyed by SQL text, as configured by
+/// [`MySqlConnectOptions::statement_cache_capacity`](crate::MySqlConnectOptions::statement_cache_capacity).
+///
+/// A capacity of `0` disables caching: [`Self::insert`] never retains anything, so every call to
+/// [`MySqlConnection::prepare_async`](crate::MySqlConnection::prepare_async) re-prepares.
+#[derive(Debug)]
+pub(crate) struct StatementCache {
+    capacity: usize,
+    entries: HashMap<String, CachedStatement>,
+    // recency order, from least to most recently used
+    order: VecDeque<String>,
+}
+
+impl StatementCache {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self { capacity, entries: HashMap::new(), order: VecDeque::new() }
+    }
+
+    pub(crate) fn get(&mut self, sql: &str) -> Option<&CachedStatement> {
+        if self.entries.contains_key(sql) {
+            self.touch(sql);
+        }
+
+        self.entries.get(sql)
+    }
+
+    /// Caches a newly prepared statement, evicting the least recently used entry if the cache
+    /// was already at capacity. Returns the evicted entry, if any, so its server-side statement
+    /// can be closed; always `None` when caching is disabled (`capacity == 0`).
+    pub(crate) fn insert(&mut self, sql: String, statement: CachedStatement) -> Option<CachedStatement> {
+        if self.capacity == 0 {
+            return None;
+        }
+
+        let evicted = if self.entries.len() >= self.capacity && !self.entries.contains_key(&sql) {
+            self.order.pop_front().and_then(|key| self.entries.remove(&key))
+        } else {
+            None
+        };
+
+        self.order.retain(|key| key != &sql);
+        self.order.push_back(sql.clone());
+        self.entries.insert(sql, statement);
+
+        evicted
+    }
+
+    fn touch(&mut self, sql: &str) {
+        if let Some(index) = self.order.iter().position(|key| key == sql) {
+            let key = self.order.remove(index).expect("index was just found");
+            self.order.push_back(key);
+        }
+    }
+
+    /// Removes and returns every cached statement, for sending `COM_STMT_CLOSE` on each as part
+    /// of [`MySqlConnection::close`](crate::MySqlConnection::close).
+    pub(crate) fn drain(&mut self) -> Vec<CachedStatement> {
+        self.order.clear();
+        self.entries.drain().map(|(_, statement)| statement).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn statement(statement_id: u32) -> CachedStatement {
+        CachedStatement { statement_id, params: Vec::new(), columns: Vec::new() }
+    }
+
+    #[test]
+    fn insert_then_get_round_trips() {
+        let mut cache = StatementCache::new(2);
+
+        assert!(cache.insert("SELECT 1".into(), statement(1)).is_none());
+
+        assert_eq!(cache.get("SELECT 1").map(|s| s.statement_id), Some(1));
+        assert!(cache.get("SELECT 2").is_none());
+    }
+
+    #[test]
+    fn insert_evicts_least_recently_used_once_at_capacity() {
+        let mut cache = StatementCache::new(2);
+
+        cache.insert("a".into(), statement(1));
+        cache.insert("b".into(), statement(2));
+
+        // inserting a third entry should evict "a", the least recently used
+        let evicted = cache.insert("c".into(), statement(3));
+
+        assert_eq!(evicted.map(|s| s.statement_id), Some(1));
+        assert!(cache.get("a").is_none());
+        assert_eq!(cache.get("b").map(|s| s.statement_id), Some(2));
+        assert_eq!(cache.get("c").map(|s| s.statement_id), Some(3));
+    }
+
+    #[test]
+    fn get_refreshes_recency_so_it_survives_eviction() {
+        let mut cache = StatementCache::new(2);
+
+        cache.insert("a".into(), statement(1));
+        cache.insert("b".into(), statement(2));
+
+        // touch "a" so "b" becomes the least recently used instead
+        cache.get("a");
+
+        let evicted = cache.insert("c".into(), statement(3));
+
+        assert_eq!(evicted.map(|s| s.statement_id), Some(2));
+        assert_eq!(cache.get("a").map(|s| s.statement_id), Some(1));
+        assert!(cache.get("b").is_none());
+    }
+
+    #[test]
+    fn insert_with_zero_capacity_disables_caching() {
+        let mut cache = StatementCache::new(0);
+
+        assert!(cache.insert("a".into(), statement(1)).is_none());
+        assert!(cache.get("a").is_none());
+    }
+
+    #[test]
+    fn drain_removes_and_returns_every_entry() {
+        let mut cache = StatementCache::new(2);
+
+        cache.insert("a".into(), statement(1));
+        cache.insert("b".into(), statement(2));
+
+        let mut drained: Vec<u32> = cache.drain().iter().map(|s| s.statement_id).collect();
+        drained.sort_unstable();
+
+        assert_eq!(drained, vec![1, 2]);
+        assert!(cache.get("a").is_none());
+        assert!(cache.get("b").is_none());
+    }
+}
@@ -0,0 +1,347 @@
+use std::io::{Read, Write};
+
+use flate2::read::ZlibDecoder;
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+
+use sqlx_core::{Error, Runtime};
+
+use super::MySqlConnection;
+
+// <https://dev.mysql.com/doc/dev/mysql-server/latest/page_protocol_basic_packets.html>
+const MAX_PACKET_SIZE: usize = 0xFF_FF_FF;
+
+// <https://dev.mysql.com/doc/dev/mysql-server/latest/page_protocol_basic_compressed_packet.html>
+//
+// packets smaller than this aren't worth the CPU cost of compressing (and may well end up
+// larger once zlib framing is added), so they're sent as-is with an `uncompressed_length` of 0
+const MIN_COMPRESSED_LEN: usize = 50;
+
+impl<Rt> MySqlConnection<Rt>
+where
+    Rt: Runtime,
+{
+    #[cfg(feature = "async")]
+    pub(crate) async fn read_packet_async(&mut self) -> sqlx_core::Result<Vec<u8>>
+    where
+        Rt: sqlx_core::Async,
+    {
+        let mut payload = Vec::new();
+
+        loop {
+            let header = self.read_raw_async(4).await?;
+            let len = u32::from_le_bytes([header[0], header[1], header[2], 0]) as usize;
+            let sequence_id = header[3];
+
+            if sequence_id != self.sequence_id {
+                return Err(Error::protocol(format!(
+                    "out-of-order packet: expected sequence id {}, got {}",
+                    self.sequence_id, sequence_id
+                )));
+            }
+
+            self.sequence_id = self.sequence_id.wrapping_add(1);
+
+            payload.extend_from_slice(&self.read_raw_async(len).await?);
+
+            if len < MAX_PACKET_SIZE {
+                break;
+            }
+        }
+
+        Ok(payload)
+    }
+
+    #[cfg(feature = "async")]
+    pub(crate) async fn write_packet_async(&mut self, payload: &[u8]) -> sqlx_core::Result<()>
+    where
+        Rt: sqlx_core::Async,
+    {
+        let framed = self.frame_packet(payload);
+        self.write_raw_async(&framed).await?;
+        self.stream.flush().await?;
+
+        Ok(())
+    }
+
+    // reads exactly `n` bytes of normal-protocol (post-decompression) data, transparently
+    // unwrapping the compressed packet framing first if `Self::compression` is active
+    #[cfg(feature = "async")]
+    async fn read_raw_async(&mut self, n: usize) -> sqlx_core::Result<Vec<u8>>
+    where
+        Rt: sqlx_core::Async,
+    {
+        if !self.compression {
+            let mut buf = vec![0_u8; n];
+            self.stream.read_exact(&mut buf).await?;
+            return Ok(buf);
+        }
+
+        while self.compression_read_buffer.len() < n {
+            let mut header = [0_u8; 7];
+            self.stream.read_exact(&mut header).await?;
+
+            let compressed_len = u32::from_le_bytes([header[0], header[1], header[2], 0]) as usize;
+            let uncompressed_len = u32::from_le_bytes([header[4], header[5], header[6], 0]) as usize;
+
+            let mut compressed = vec![0_u8; compressed_len];
+            self.stream.read_exact(&mut compressed).await?;
+
+            decompress_into(&mut self.compression_read_buffer, &compressed, uncompressed_len)?;
+        }
+
+        Ok(self.compression_read_buffer.drain(..n).collect())
+    }
+
+    // writes already-framed normal-protocol bytes to the wire, wrapping them in one or more
+    // compressed packets first if `Self::compression` is active
+    #[cfg(feature = "async")]
+    async fn write_raw_async(&mut self, framed: &[u8]) -> sqlx_core::Result<()>
+    where
+        Rt: sqlx_core::Async,
+    {
+        if !self.compression {
+            self.stream.write(framed).await?;
+            return Ok(());
+        }
+
+        for chunk in framed.chunks(MAX_PACKET_SIZE) {
+            let (header, body) = self.compress_packet(chunk)?;
+            self.stream.write(&header).await?;
+            self.stream.write(&body).await?;
+        }
+
+        Ok(())
+    }
+
+    // builds the normal (uncompressed) packet framing for `payload` -- the 4-byte
+    // length-and-sequence-id header in front of every chunk of at most `MAX_PACKET_SIZE`
+    // bytes -- advancing `sequence_id` once per chunk
+    fn frame_packet(&mut self, payload: &[u8]) -> Vec<u8> {
+        frame_packet_bytes(payload, &mut self.sequence_id)
+    }
+
+    // wraps one chunk of already-framed, normal-protocol bytes in a compressed packet
+    // header, deflating the body when it's long enough to be worth it
+    fn compress_packet(&mut self, chunk: &[u8]) -> sqlx_core::Result<(Vec<u8>, Vec<u8>)> {
+        compress_packet_bytes(chunk, &mut self.compression_sequence_id)
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<Rt> MySqlConnection<Rt>
+where
+    Rt: sqlx_core::blocking::Runtime,
+{
+    pub(crate) fn read_packet(&mut self) -> sqlx_core::Result<Vec<u8>> {
+        let mut payload = Vec::new();
+
+        loop {
+            let header = self.read_raw(4)?;
+            let len = u32::from_le_bytes([header[0], header[1], header[2], 0]) as usize;
+            let sequence_id = header[3];
+
+            if sequence_id != self.sequence_id {
+                return Err(Error::protocol(format!(
+                    "out-of-order packet: expected sequence id {}, got {}",
+                    self.sequence_id, sequence_id
+                )));
+            }
+
+            self.sequence_id = self.sequence_id.wrapping_add(1);
+
+            payload.extend_from_slice(&self.read_raw(len)?);
+
+            if len < MAX_PACKET_SIZE {
+                break;
+            }
+        }
+
+        Ok(payload)
+    }
+
+    pub(crate) fn write_packet(&mut self, payload: &[u8]) -> sqlx_core::Result<()> {
+        let framed = self.frame_packet(payload);
+        self.write_raw(&framed)?;
+        self.stream.flush()?;
+
+        Ok(())
+    }
+
+    fn read_raw(&mut self, n: usize) -> sqlx_core::Result<Vec<u8>> {
+        if !self.compression {
+            let mut buf = vec![0_u8; n];
+            self.stream.read_exact(&mut buf)?;
+            return Ok(buf);
+        }
+
+        while self.compression_read_buffer.len() < n {
+            let mut header = [0_u8; 7];
+            self.stream.read_exact(&mut header)?;
+
+            let compressed_len = u32::from_le_bytes([header[0], header[1], header[2], 0]) as usize;
+            let uncompressed_len = u32::from_le_bytes([header[4], header[5], header[6], 0]) as usize;
+
+            let mut compressed = vec![0_u8; compressed_len];
+            self.stream.read_exact(&mut compressed)?;
+
+            decompress_into(&mut self.compression_read_buffer, &compressed, uncompressed_len)?;
+        }
+
+        Ok(self.compression_read_buffer.drain(..n).collect())
+    }
+
+    fn write_raw(&mut self, framed: &[u8]) -> sqlx_core::Result<()> {
+        if !self.compression {
+            self.stream.write(framed)?;
+            return Ok(());
+        }
+
+        for chunk in framed.chunks(MAX_PACKET_SIZE) {
+            let (header, body) = self.compress_packet(chunk)?;
+            self.stream.write(&header)?;
+            self.stream.write(&body)?;
+        }
+
+        Ok(())
+    }
+}
+
+// builds the normal (uncompressed) packet framing for `payload` -- the 4-byte
+// length-and-sequence-id header in front of every chunk of at most `MAX_PACKET_SIZE` bytes --
+// advancing `sequence_id` once per chunk; split out from `MySqlConnection::frame_packet` so it
+// can be exercised without a live connection
+fn frame_packet_bytes(payload: &[u8], sequence_id: &mut u8) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(payload.len() + 4);
+    let mut chunks = payload.chunks(MAX_PACKET_SIZE).peekable();
+
+    // an empty payload is still framed as a single, empty packet
+    if chunks.peek().is_none() {
+        frame_packet_chunk(&[], sequence_id, &mut buf);
+    }
+
+    while let Some(chunk) = chunks.next() {
+        frame_packet_chunk(chunk, sequence_id, &mut buf);
+
+        // a chunk exactly at the maximum size must be followed by a zero-length packet so
+        // the server knows the payload has ended
+        if chunk.len() == MAX_PACKET_SIZE && chunks.peek().is_none() {
+            frame_packet_chunk(&[], sequence_id, &mut buf);
+        }
+    }
+
+    buf
+}
+
+fn frame_packet_chunk(chunk: &[u8], sequence_id: &mut u8, buf: &mut Vec<u8>) {
+    let len = (chunk.len() as u32).to_le_bytes();
+
+    buf.extend_from_slice(&len[..3]);
+    buf.push(*sequence_id);
+    buf.extend_from_slice(chunk);
+
+    *sequence_id = sequence_id.wrapping_add(1);
+}
+
+// wraps one chunk of already-framed, normal-protocol bytes in a compressed packet header,
+// deflating the body when it's long enough to be worth it; split out from
+// `MySqlConnection::compress_packet` so it can be exercised without a live connection
+fn compress_packet_bytes(chunk: &[u8], compression_sequence_id: &mut u8) -> sqlx_core::Result<(Vec<u8>, Vec<u8>)> {
+    let (uncompressed_len, body) = if chunk.len() < MIN_COMPRESSED_LEN {
+        (0, chunk.to_vec())
+    } else {
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder
+            .write_all(chunk)
+            .map_err(|error| Error::protocol(format!("failed to compress packet: {error}")))?;
+        let body = encoder
+            .finish()
+            .map_err(|error| Error::protocol(format!("failed to compress packet: {error}")))?;
+
+        (chunk.len(), body)
+    };
+
+    let mut header = Vec::with_capacity(7);
+    header.extend_from_slice(&(body.len() as u32).to_le_bytes()[..3]);
+    header.push(*compression_sequence_id);
+    header.extend_from_slice(&(uncompressed_len as u32).to_le_bytes()[..3]);
+
+    *compression_sequence_id = compression_sequence_id.wrapping_add(1);
+
+    Ok((header, body))
+}
+
+// decompresses one compressed packet's body and appends the resulting normal-protocol
+// bytes to `out`; `uncompressed_len` of 0 means the body was sent verbatim (it wasn't
+// worth compressing), per the compressed packet header format
+fn decompress_into(out: &mut Vec<u8>, compressed: &[u8], uncompressed_len: usize) -> sqlx_core::Result<()> {
+    if uncompressed_len == 0 {
+        out.extend_from_slice(compressed);
+    } else {
+        let mut decompressed = Vec::with_capacity(uncompressed_len);
+        ZlibDecoder::new(compressed)
+            .read_to_end(&mut decompressed)
+            .map_err(|error| Error::protocol(format!("failed to decompress packet: {error}")))?;
+
+        out.extend_from_slice(&decompressed);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_packet_bytes_includes_header_and_advances_sequence_id() {
+        let mut sequence_id = 5;
+        let framed = frame_packet_bytes(b"abc", &mut sequence_id);
+
+        assert_eq!(framed, vec![3, 0, 0, 5, b'a', b'b', b'c']);
+        assert_eq!(sequence_id, 6);
+    }
+
+    #[test]
+    fn frame_packet_bytes_frames_empty_payload_as_one_empty_packet() {
+        let mut sequence_id = 0;
+        let framed = frame_packet_bytes(b"", &mut sequence_id);
+
+        assert_eq!(framed, vec![0, 0, 0, 0]);
+        assert_eq!(sequence_id, 1);
+    }
+
+    #[test]
+    fn compress_packet_bytes_stores_short_chunks_verbatim() {
+        let mut compression_sequence_id = 0;
+        let chunk = vec![1_u8; MIN_COMPRESSED_LEN - 1];
+        let (header, body) = compress_packet_bytes(&chunk, &mut compression_sequence_id).unwrap();
+
+        // uncompressed_len of 0 in the header means "stored verbatim"
+        assert_eq!(&header[4..7], &[0, 0, 0]);
+        assert_eq!(body, chunk);
+        assert_eq!(compression_sequence_id, 1);
+    }
+
+    #[test]
+    fn compress_packet_bytes_deflates_long_chunks_and_round_trips() {
+        let mut compression_sequence_id = 0;
+        let chunk = vec![7_u8; MIN_COMPRESSED_LEN + 1];
+        let (header, body) = compress_packet_bytes(&chunk, &mut compression_sequence_id).unwrap();
+
+        let uncompressed_len = u32::from_le_bytes([header[4], header[5], header[6], 0]) as usize;
+        assert_eq!(uncompressed_len, chunk.len());
+
+        let mut out = Vec::new();
+        decompress_into(&mut out, &body, uncompressed_len).unwrap();
+        assert_eq!(out, chunk);
+    }
+
+    #[test]
+    fn decompress_into_passes_through_when_uncompressed_len_is_zero() {
+        let mut out = Vec::new();
+        decompress_into(&mut out, b"verbatim", 0).unwrap();
+
+        assert_eq!(out, b"verbatim");
+    }
+}
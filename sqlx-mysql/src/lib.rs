@@ -0,0 +1,18 @@
+//! MySQL driver implementation for SQLx.
+
+mod connection;
+mod options;
+pub mod protocol;
+#[cfg(feature = "native")]
+pub mod server;
+mod transport;
+
+pub use connection::{MySqlConnection, MySqlQueryResult, MySqlStatement};
+pub use options::{MySqlConnectOptions, MySqlSslMode};
+
+#[cfg(not(feature = "native"))]
+pub use transport::WasmStream;
+
+/// The MySQL database driver.
+#[derive(Debug)]
+pub struct MySql;
@@ -0,0 +1,312 @@
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+
+use sqlx_core::Runtime;
+
+mod parse;
+mod ssl_mode;
+
+pub use ssl_mode::MySqlSslMode;
+
+/// Options and flags which can be used to configure a MySQL connection.
+///
+/// A value of `MySqlConnectOptions` can be parsed from a connection URL,
+/// as described by [`FromStr`][std::str::FromStr].
+#[allow(clippy::module_name_repetitions)]
+pub struct MySqlConnectOptions<Rt>
+where
+    Rt: Runtime,
+{
+    host: String,
+    port: u16,
+    socket: Option<PathBuf>,
+    username: Option<String>,
+    password: Option<String>,
+    database: Option<String>,
+    charset: String,
+    timezone: String,
+
+    ssl_mode: MySqlSslMode,
+    ssl_ca: Option<PathBuf>,
+    ssl_cert: Option<PathBuf>,
+    ssl_key: Option<PathBuf>,
+
+    multi_statements: bool,
+    compression: bool,
+    statement_cache_capacity: usize,
+
+    runtime: PhantomData<Rt>,
+}
+
+impl<Rt> MySqlConnectOptions<Rt>
+where
+    Rt: Runtime,
+{
+    /// Creates a new, default set of options ready for configuration.
+    pub fn new() -> Self {
+        Self {
+            host: String::from("localhost"),
+            port: 3306,
+            socket: None,
+            username: None,
+            password: None,
+            database: None,
+            charset: String::from("utf8mb4"),
+            timezone: String::from("utc"),
+
+            ssl_mode: MySqlSslMode::default(),
+            ssl_ca: None,
+            ssl_cert: None,
+            ssl_key: None,
+
+            multi_statements: false,
+            compression: false,
+            statement_cache_capacity: 100,
+
+            runtime: PhantomData,
+        }
+    }
+
+    /// Sets the hostname to connect to.
+    pub fn host(&mut self, host: impl Into<String>) -> &mut Self {
+        self.host = host.into();
+        self
+    }
+
+    /// Gets the hostname to connect to.
+    pub fn get_host(&self) -> &str {
+        &self.host
+    }
+
+    /// Sets the port to connect to.
+    pub fn port(&mut self, port: u16) -> &mut Self {
+        self.port = port;
+        self
+    }
+
+    /// Gets the port to connect to.
+    pub fn get_port(&self) -> u16 {
+        self.port
+    }
+
+    /// Sets the path to a Unix domain socket to connect to, in place of a TCP connection.
+    pub fn socket(&mut self, socket: impl AsRef<Path>) -> &mut Self {
+        self.socket = Some(socket.as_ref().to_path_buf());
+        self
+    }
+
+    /// Gets the path to a Unix domain socket to connect to, if one was set.
+    pub fn get_socket(&self) -> Option<&Path> {
+        self.socket.as_deref()
+    }
+
+    /// Sets the username to authenticate with.
+    pub fn username(&mut self, username: impl Into<String>) -> &mut Self {
+        self.username = Some(username.into());
+        self
+    }
+
+    /// Gets the username to authenticate with, if one was set.
+    pub fn get_username(&self) -> Option<&str> {
+        self.username.as_deref()
+    }
+
+    /// Sets the password to authenticate with.
+    pub fn password(&mut self, password: impl Into<String>) -> &mut Self {
+        self.password = Some(password.into());
+        self
+    }
+
+    /// Gets the password to authenticate with, if one was set.
+    pub fn get_password(&self) -> Option<&str> {
+        self.password.as_deref()
+    }
+
+    /// Sets the default database for the connection.
+    pub fn database(&mut self, database: impl Into<String>) -> &mut Self {
+        self.database = Some(database.into());
+        self
+    }
+
+    /// Gets the default database for the connection, if one was set.
+    pub fn get_database(&self) -> Option<&str> {
+        self.database.as_deref()
+    }
+
+    /// Sets the character set for the connection.
+    pub fn charset(&mut self, charset: impl Into<String>) -> &mut Self {
+        self.charset = charset.into();
+        self
+    }
+
+    /// Gets the character set for the connection.
+    pub fn get_charset(&self) -> &str {
+        &self.charset
+    }
+
+    /// Sets the timezone for the connection.
+    pub fn timezone(&mut self, timezone: impl Into<String>) -> &mut Self {
+        self.timezone = timezone.into();
+        self
+    }
+
+    /// Gets the timezone for the connection.
+    pub fn get_timezone(&self) -> &str {
+        &self.timezone
+    }
+
+    /// Sets the level of TLS to require of the connection to the server.
+    ///
+    /// By default, this is [`MySqlSslMode::Preferred`].
+    pub fn ssl_mode(&mut self, mode: MySqlSslMode) -> &mut Self {
+        self.ssl_mode = mode;
+        self
+    }
+
+    /// Gets the level of TLS required of the connection to the server.
+    pub fn get_ssl_mode(&self) -> MySqlSslMode {
+        self.ssl_mode
+    }
+
+    /// Sets the path to a PEM file containing the certificate authority used to validate the
+    /// server's TLS certificate, for use with [`MySqlSslMode::VerifyCa`] and
+    /// [`MySqlSslMode::VerifyIdentity`].
+    pub fn ssl_ca(&mut self, path: impl AsRef<Path>) -> &mut Self {
+        self.ssl_ca = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Gets the path to the certificate authority PEM file, if one was set.
+    pub fn get_ssl_ca(&self) -> Option<&Path> {
+        self.ssl_ca.as_deref()
+    }
+
+    /// Sets the path to a PEM file containing a client certificate for mutual TLS.
+    pub fn ssl_cert(&mut self, path: impl AsRef<Path>) -> &mut Self {
+        self.ssl_cert = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Gets the path to the client certificate PEM file, if one was set.
+    pub fn get_ssl_cert(&self) -> Option<&Path> {
+        self.ssl_cert.as_deref()
+    }
+
+    /// Sets the path to a PEM file containing the private key for [`Self::ssl_cert`].
+    pub fn ssl_key(&mut self, path: impl AsRef<Path>) -> &mut Self {
+        self.ssl_key = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Gets the path to the client private key PEM file, if one was set.
+    pub fn get_ssl_key(&self) -> Option<&Path> {
+        self.ssl_key.as_deref()
+    }
+
+    /// Sets whether a single [`query`](crate::MySqlConnection) call may submit more than one
+    /// semicolon-separated SQL statement.
+    ///
+    /// Disabled by default, as it changes how the server treats the submitted SQL and is a
+    /// common vector for SQL injection when not opted into deliberately.
+    pub fn multi_statements(&mut self, multi_statements: bool) -> &mut Self {
+        self.multi_statements = multi_statements;
+        self
+    }
+
+    /// Gets whether a single `query` call may submit more than one semicolon-separated SQL
+    /// statement.
+    pub fn get_multi_statements(&self) -> bool {
+        self.multi_statements
+    }
+
+    /// Sets whether to negotiate packet compression with the server.
+    ///
+    /// Disabled by default. Worth enabling on high-latency or bandwidth-constrained links;
+    /// on a fast local connection the extra CPU work outweighs the savings.
+    pub fn compression(&mut self, compression: bool) -> &mut Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Gets whether to negotiate packet compression with the server.
+    pub fn get_compression(&self) -> bool {
+        self.compression
+    }
+
+    /// Sets how many prepared statements [`MySqlConnection`](crate::MySqlConnection) keeps
+    /// cached (by SQL text) at once, so that repeated calls to
+    /// [`prepare_async`](crate::MySqlConnection::prepare_async) with the same SQL reuse the
+    /// server-assigned statement instead of re-preparing it.
+    ///
+    /// Defaults to 100. Set to `0` to disable the cache outright, re-preparing every time.
+    pub fn statement_cache_capacity(&mut self, capacity: usize) -> &mut Self {
+        self.statement_cache_capacity = capacity;
+        self
+    }
+
+    /// Gets how many prepared statements are kept cached at once.
+    pub fn get_statement_cache_capacity(&self) -> usize {
+        self.statement_cache_capacity
+    }
+}
+
+impl<Rt> Default for MySqlConnectOptions<Rt>
+where
+    Rt: Runtime,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Rt> Clone for MySqlConnectOptions<Rt>
+where
+    Rt: Runtime,
+{
+    fn clone(&self) -> Self {
+        Self {
+            host: self.host.clone(),
+            port: self.port,
+            socket: self.socket.clone(),
+            username: self.username.clone(),
+            password: self.password.clone(),
+            database: self.database.clone(),
+            charset: self.charset.clone(),
+            timezone: self.timezone.clone(),
+
+            ssl_mode: self.ssl_mode,
+            ssl_ca: self.ssl_ca.clone(),
+            ssl_cert: self.ssl_cert.clone(),
+            ssl_key: self.ssl_key.clone(),
+
+            multi_statements: self.multi_statements,
+            compression: self.compression,
+            statement_cache_capacity: self.statement_cache_capacity,
+
+            runtime: PhantomData,
+        }
+    }
+}
+
+impl<Rt> std::fmt::Debug for MySqlConnectOptions<Rt>
+where
+    Rt: Runtime,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MySqlConnectOptions")
+            .field("host", &self.host)
+            .field("port", &self.port)
+            .field("socket", &self.socket)
+            .field("username", &self.username)
+            .field("database", &self.database)
+            .field("charset", &self.charset)
+            .field("timezone", &self.timezone)
+            .field("ssl_mode", &self.ssl_mode)
+            .field("ssl_ca", &self.ssl_ca)
+            .field("ssl_cert", &self.ssl_cert)
+            .field("multi_statements", &self.multi_statements)
+            .field("compression", &self.compression)
+            .field("statement_cache_capacity", &self.statement_cache_capacity)
+            .finish()
+    }
+}
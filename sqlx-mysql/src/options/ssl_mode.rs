@@ -0,0 +1,45 @@
+use std::str::FromStr;
+
+/// Options for controlling the level of TLS used for a connection to MySQL.
+///
+/// Parsed from the `ssl-mode` (or `sslmode`/`sslMode`/`tls`) query parameter of a
+/// connection URL.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum MySqlSslMode {
+    /// Never use TLS, even if the server supports it.
+    Disabled,
+
+    /// Use TLS if the server supports it, falling back to an unencrypted connection if not.
+    #[default]
+    Preferred,
+
+    /// Always use TLS and fail to connect if the server does not support it. Does not verify
+    /// the server's certificate chain or hostname.
+    Required,
+
+    /// Always use TLS and verify the server's certificate chain, but not its hostname.
+    VerifyCa,
+
+    /// Always use TLS, verifying both the server's certificate chain and its hostname.
+    VerifyIdentity,
+}
+
+impl FromStr for MySqlSslMode {
+    type Err = sqlx_core::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match &*s.to_ascii_lowercase() {
+            "disabled" => MySqlSslMode::Disabled,
+            "preferred" => MySqlSslMode::Preferred,
+            "required" => MySqlSslMode::Required,
+            "verify_ca" | "verify-ca" => MySqlSslMode::VerifyCa,
+            "verify_identity" | "verify-identity" => MySqlSslMode::VerifyIdentity,
+
+            _ => {
+                return Err(sqlx_core::Error::configuration_msg(format!(
+                    "unknown value {s:?} for `ssl-mode`"
+                )));
+            }
+        })
+    }
+}
@@ -0,0 +1,63 @@
+use sha1::{Digest, Sha1};
+
+/// Computes the `mysql_native_password` auth response for a given password and server
+/// scramble.
+///
+/// `token = SHA1(password) XOR SHA1(scramble ++ SHA1(SHA1(password)))`
+///
+/// Used by [`MySqlConnection`](crate::MySqlConnection) to answer a server's challenge, and
+/// exposed so that a downstream crate implementing a MySQL-protocol-compatible server can
+/// issue the same challenge to a connecting client.
+///
+/// <https://dev.mysql.com/doc/dev/mysql-server/latest/page_protocol_connection_phase_authentication_methods_native_password_authentication.html>
+pub fn native_password_response(password: &str, scramble: &[u8]) -> Vec<u8> {
+    let password_sha1 = Sha1::digest(password.as_bytes());
+    let scramble_hash = native_password_scramble_hash(&password_sha1, scramble);
+
+    password_sha1.iter().zip(scramble_hash).map(|(a, b)| a ^ b).collect()
+}
+
+/// Verifies a `mysql_native_password` auth response against the password it was computed
+/// from and the scramble it was challenged with.
+pub fn native_password_verify(password: &str, scramble: &[u8], response: &[u8]) -> bool {
+    native_password_response(password, scramble) == response
+}
+
+fn native_password_scramble_hash(
+    password_sha1: &[u8],
+    scramble: &[u8],
+) -> impl Iterator<Item = u8> {
+    let password_sha1_sha1 = Sha1::digest(password_sha1);
+
+    let mut hasher = Sha1::new();
+    hasher.update(scramble);
+    hasher.update(password_sha1_sha1);
+
+    hasher.finalize().into_iter()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // independently computed from `SHA1(password) XOR SHA1(scramble ++ SHA1(SHA1(password)))`
+    const SCRAMBLE: [u8; 20] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20];
+    const TOKEN: [u8; 20] = [
+        244, 179, 188, 159, 83, 136, 46, 147, 198, 176, 1, 92, 217, 183, 27, 221, 234, 14, 204, 209,
+    ];
+
+    #[test]
+    fn native_password_response_matches_known_vector() {
+        assert_eq!(native_password_response("sqlx_pw", &SCRAMBLE), TOKEN);
+    }
+
+    #[test]
+    fn native_password_verify_accepts_matching_response() {
+        assert!(native_password_verify("sqlx_pw", &SCRAMBLE, &TOKEN));
+    }
+
+    #[test]
+    fn native_password_verify_rejects_wrong_password() {
+        assert!(!native_password_verify("not_the_pw", &SCRAMBLE, &TOKEN));
+    }
+}
@@ -0,0 +1,102 @@
+use sqlx_core::Error;
+
+/// A cursor over a packet payload, with helpers for the primitive encodings used throughout
+/// the MySQL wire protocol (fixed-length integers, NUL-terminated and length-encoded
+/// strings, length-encoded integers).
+///
+/// <https://dev.mysql.com/doc/dev/mysql-server/latest/page_protocol_basic_dt.html>
+pub(crate) struct Buf<'de> {
+    bytes: &'de [u8],
+}
+
+impl<'de> Buf<'de> {
+    pub(crate) fn new(bytes: &'de [u8]) -> Self {
+        Self { bytes }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    pub(crate) fn remaining(&self) -> &'de [u8] {
+        self.bytes
+    }
+
+    pub(crate) fn take_u8(&mut self) -> Result<u8, Error> {
+        let (&byte, rest) = self.bytes.split_first().ok_or_else(eof)?;
+        self.bytes = rest;
+        Ok(byte)
+    }
+
+    pub(crate) fn take_u16(&mut self) -> Result<u16, Error> {
+        let bytes = self.take_bytes(2)?;
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    pub(crate) fn take_u32(&mut self) -> Result<u32, Error> {
+        let bytes = self.take_bytes(4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    pub(crate) fn take_u64(&mut self) -> Result<u64, Error> {
+        let bytes = self.take_bytes(8)?;
+        Ok(u64::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    pub(crate) fn take_bytes(&mut self, n: usize) -> Result<&'de [u8], Error> {
+        if self.bytes.len() < n {
+            return Err(eof());
+        }
+
+        let (taken, rest) = self.bytes.split_at(n);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    pub(crate) fn take_nul_string(&mut self) -> Result<String, Error> {
+        let nul = self.bytes.iter().position(|&b| b == 0).ok_or_else(eof)?;
+        let (taken, rest) = self.bytes.split_at(nul);
+        self.bytes = &rest[1..];
+
+        Ok(String::from_utf8_lossy(taken).into_owned())
+    }
+
+    /// Reads a length-encoded integer.
+    ///
+    /// <https://dev.mysql.com/doc/dev/mysql-server/latest/page_protocol_basic_dt_integers.html#sect_protocol_basic_dt_int_le>
+    pub(crate) fn take_int_lenenc(&mut self) -> Result<u64, Error> {
+        Ok(match self.take_u8()? {
+            0xfb => return Err(Error::protocol("unexpected NULL length-encoded integer")),
+            0xfc => u64::from(self.take_u16()?),
+            0xfd => {
+                let bytes = self.take_bytes(3)?;
+                u64::from(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], 0]))
+            }
+            0xfe => self.take_u64()?,
+            byte => u64::from(byte),
+        })
+    }
+
+    /// Reads a length-encoded string, returning `None` for a length-encoded `NULL`.
+    pub(crate) fn take_bytes_lenenc(&mut self) -> Result<Option<&'de [u8]>, Error> {
+        match self.bytes.first() {
+            Some(0xfb) => {
+                self.bytes = &self.bytes[1..];
+                Ok(None)
+            }
+
+            _ => {
+                let len = self.take_int_lenenc()? as usize;
+                self.take_bytes(len).map(Some)
+            }
+        }
+    }
+
+    pub(crate) fn take_rest(&mut self) -> &'de [u8] {
+        std::mem::take(&mut self.bytes)
+    }
+}
+
+fn eof() -> Error {
+    Error::protocol("unexpected eof decoding packet")
+}
@@ -0,0 +1,107 @@
+use bitflags::bitflags;
+
+bitflags! {
+    /// Capability flags are used by the client and server to indicate which features they
+    /// support and want to use.
+    ///
+    /// <https://dev.mysql.com/doc/dev/mysql-server/latest/group__group__cs__capabilities__flags.html>
+    #[derive(Default)]
+    pub struct Capabilities: u64 {
+        /// Use the improved version of Old Password Authentication.
+        const LONG_PASSWORD = 1;
+
+        /// Send found rows instead of affected rows in `EOF_Packet`.
+        const FOUND_ROWS = 1 << 1;
+
+        /// Get all column flags.
+        const LONG_FLAG = 1 << 2;
+
+        /// Database (schema) name can be specified on connect in Handshake Response Packet.
+        const CONNECT_WITH_DB = 1 << 3;
+
+        /// Don't allow `database.table.column`.
+        const NO_SCHEMA = 1 << 4;
+
+        /// Compression protocol supported.
+        const COMPRESS = 1 << 5;
+
+        /// Special handling of multi-statement and multi-result.
+        const ODBC = 1 << 6;
+
+        /// Can use `LOAD DATA LOCAL`.
+        const LOCAL_FILES = 1 << 7;
+
+        /// Ignore spaces before `(`.
+        const IGNORE_SPACE = 1 << 8;
+
+        /// New 4.1 protocol.
+        const PROTOCOL_41 = 1 << 9;
+
+        /// This is an interactive client.
+        const INTERACTIVE = 1 << 10;
+
+        /// Use SSL encryption for the session.
+        const SSL = 1 << 11;
+
+        /// Client only flag, not used.
+        const IGNORE_SIGPIPE = 1 << 12;
+
+        /// Client knows about transactions.
+        const TRANSACTIONS = 1 << 13;
+
+        /// Old flag for 4.1 protocol.
+        const RESERVED = 1 << 14;
+
+        /// Old flag for 4.1 authentication, required by the client.
+        const SECURE_CONNECTION = 1 << 15;
+
+        /// Enable/disable multi-statements support.
+        const MULTI_STATEMENTS = 1 << 16;
+
+        /// Enable/disable multi-results support.
+        const MULTI_RESULTS = 1 << 17;
+
+        /// Multi-results and OUT parameters in PS protocol.
+        const PS_MULTI_RESULTS = 1 << 18;
+
+        /// Client supports plugin authentication.
+        const PLUGIN_AUTH = 1 << 19;
+
+        /// Client supports connection attributes.
+        const CONNECT_ATTRS = 1 << 20;
+
+        /// Enable authentication response packet to be larger than 255 bytes.
+        const PLUGIN_AUTH_LENENC_DATA = 1 << 21;
+
+        /// Don't close the connection for a user account with expired password.
+        const CAN_HANDLE_EXPIRED_PASSWORDS = 1 << 22;
+
+        /// Capable of handling server state change information.
+        const SESSION_TRACK = 1 << 23;
+
+        /// Client no longer needs `EOF_Packet` and will use `OK_Packet` instead.
+        const DEPRECATE_EOF = 1 << 24;
+
+        /// The client can handle optional metadata information on the resultset.
+        const OPTIONAL_RESULTSET_METADATA = 1 << 25;
+
+        /// Compression protocol extended to support zstd compression method.
+        const ZSTD_COMPRESSION_ALGORITHM = 1 << 26;
+
+        /// Support optional extension for query parameters into the `COM_QUERY` and
+        /// `COM_STMT_EXECUTE` packets.
+        const QUERY_ATTRIBUTES = 1 << 27;
+
+        /// Support for better multi-factor authentication.
+        const MULTI_FACTOR_AUTHENTICATION = 1 << 28;
+
+        /// This flag will be reserved to extend the 32-bit capabilities structure to 64 bits.
+        const CAPABILITY_EXTENSION = 1 << 29;
+
+        /// Verify server certificate.
+        const SSL_VERIFY_SERVER_CERT = 1 << 30;
+
+        /// Don't reset the options after an unsuccessful connect.
+        const REMEMBER_OPTIONS = 1 << 31;
+    }
+}
@@ -0,0 +1,94 @@
+use sqlx_core::Error;
+
+use crate::protocol::buf::Buf;
+use crate::protocol::{Capabilities, Decode, Encode};
+
+// <https://dev.mysql.com/doc/dev/mysql-server/latest/page_protocol_command_phase.html>
+const COM_QUIT: u8 = 0x01;
+const COM_INIT_DB: u8 = 0x02;
+const COM_QUERY: u8 = 0x03;
+const COM_STMT_PREPARE: u8 = 0x16;
+const COM_STMT_CLOSE: u8 = 0x19;
+const COM_PING: u8 = 0x0e;
+
+/// A command-phase request sent by a client, as received by a server implementation.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    /// `COM_QUIT`: the client is ending the session.
+    Quit,
+
+    /// `COM_INIT_DB`: change the default schema.
+    InitDb(String),
+
+    /// `COM_QUERY`: a single SQL statement (or, with `CLIENT_MULTI_STATEMENTS`, a
+    /// semicolon-separated batch) to execute directly, without a prepared statement.
+    Query(String),
+
+    /// `COM_STMT_PREPARE`: prepare a SQL statement for later (repeated) execution, returning a
+    /// server-assigned statement id.
+    Prepare(String),
+
+    /// `COM_STMT_CLOSE`: discard a previously prepared statement, identified by the id the
+    /// server assigned it in response to `COM_STMT_PREPARE`. The server sends no response.
+    StmtClose(u32),
+
+    /// `COM_PING`: check that the connection is alive.
+    Ping,
+
+    /// Any other command, identified by its command byte, with the remainder of the packet
+    /// left undecoded.
+    Other { code: u8, payload: Vec<u8> },
+}
+
+impl<'de> Decode<'de> for Command {
+    fn decode_with(buf: &'de [u8], _: Capabilities) -> Result<Self, Error> {
+        let mut buf = Buf::new(buf);
+
+        let code = buf.take_u8()?;
+
+        Ok(match code {
+            COM_QUIT => Command::Quit,
+            COM_INIT_DB => Command::InitDb(String::from_utf8_lossy(buf.take_rest()).into_owned()),
+            COM_QUERY => Command::Query(String::from_utf8_lossy(buf.take_rest()).into_owned()),
+            COM_STMT_PREPARE => Command::Prepare(String::from_utf8_lossy(buf.take_rest()).into_owned()),
+            COM_STMT_CLOSE => Command::StmtClose(buf.take_u32()?),
+            COM_PING => Command::Ping,
+            code => Command::Other { code, payload: buf.take_rest().to_vec() },
+        })
+    }
+}
+
+impl Encode for Command {
+    fn encode(&self, buf: &mut Vec<u8>, _: Capabilities) {
+        match self {
+            Command::Quit => buf.push(COM_QUIT),
+
+            Command::InitDb(schema) => {
+                buf.push(COM_INIT_DB);
+                buf.extend_from_slice(schema.as_bytes());
+            }
+
+            Command::Query(sql) => {
+                buf.push(COM_QUERY);
+                buf.extend_from_slice(sql.as_bytes());
+            }
+
+            Command::Prepare(sql) => {
+                buf.push(COM_STMT_PREPARE);
+                buf.extend_from_slice(sql.as_bytes());
+            }
+
+            Command::StmtClose(statement_id) => {
+                buf.push(COM_STMT_CLOSE);
+                buf.extend_from_slice(&statement_id.to_le_bytes());
+            }
+
+            Command::Ping => buf.push(COM_PING),
+
+            Command::Other { code, payload } => {
+                buf.push(*code);
+                buf.extend_from_slice(payload);
+            }
+        }
+    }
+}
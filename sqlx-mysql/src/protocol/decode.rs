@@ -0,0 +1,12 @@
+use sqlx_core::Error;
+
+use crate::protocol::Capabilities;
+
+/// A type that can be decoded from a MySQL wire protocol packet.
+pub(crate) trait Decode<'de>: Sized {
+    fn decode(buf: &'de [u8]) -> Result<Self, Error> {
+        Self::decode_with(buf, Capabilities::empty())
+    }
+
+    fn decode_with(buf: &'de [u8], capabilities: Capabilities) -> Result<Self, Error>;
+}
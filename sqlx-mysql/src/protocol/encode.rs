@@ -0,0 +1,11 @@
+use crate::protocol::Capabilities;
+
+/// A type that can be encoded into a MySQL wire protocol packet.
+///
+/// `pub` (rather than `pub(crate)`, like [`Decode`](super::Decode)) because
+/// [`MySqlServerConnection`](crate::server::MySqlServerConnection) accepts any `Encode` type
+/// in its public API so a server implementation can send `OK`/`ERR`/`EOF` and command-phase
+/// packets without this crate needing to know about every one in advance.
+pub trait Encode {
+    fn encode(&self, buf: &mut Vec<u8>, capabilities: Capabilities);
+}
@@ -0,0 +1,69 @@
+use sqlx_core::Error;
+
+use crate::protocol::buf::Buf;
+use crate::protocol::{Capabilities, Decode};
+
+/// The initial handshake packet sent by the server immediately after the connection is
+/// established.
+///
+/// <https://dev.mysql.com/doc/dev/mysql-server/latest/page_protocol_connection_phase_packets_protocol_handshake_v10.html>
+#[derive(Debug)]
+pub(crate) struct Handshake {
+    pub(crate) protocol_version: u8,
+    pub(crate) server_version: String,
+    pub(crate) connection_id: u32,
+    pub(crate) auth_plugin_data: Vec<u8>,
+    pub(crate) capabilities: Capabilities,
+    pub(crate) charset: u8,
+    pub(crate) status: u16,
+    pub(crate) auth_plugin_name: Option<String>,
+}
+
+impl<'de> Decode<'de> for Handshake {
+    fn decode_with(buf: &'de [u8], _: Capabilities) -> Result<Self, Error> {
+        let mut buf = Buf::new(buf);
+
+        let protocol_version = buf.take_u8()?;
+        let server_version = buf.take_nul_string()?;
+        let connection_id = buf.take_u32()?;
+
+        let mut auth_plugin_data = buf.take_bytes(8)?.to_vec();
+
+        // filler
+        let _ = buf.take_u8()?;
+
+        let capabilities_lower = u32::from(buf.take_u16()?);
+        let charset = buf.take_u8()?;
+        let status = buf.take_u16()?;
+        let capabilities_upper = u32::from(buf.take_u16()?);
+
+        let capabilities =
+            Capabilities::from_bits_truncate(u64::from(capabilities_lower | (capabilities_upper << 16)));
+
+        let auth_plugin_data_len = buf.take_u8()?;
+
+        // 10 bytes reserved, all zero
+        buf.take_bytes(10)?;
+
+        let auth_plugin_name = if capabilities.contains(Capabilities::PLUGIN_AUTH) {
+            let len = (auth_plugin_data_len as usize).saturating_sub(8).max(13);
+            let rest = buf.take_bytes(len)?;
+            auth_plugin_data.extend_from_slice(&rest[..rest.len().saturating_sub(1)]);
+
+            Some(buf.take_nul_string()?)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            protocol_version,
+            server_version,
+            connection_id,
+            auth_plugin_data,
+            capabilities,
+            charset,
+            status,
+            auth_plugin_name,
+        })
+    }
+}
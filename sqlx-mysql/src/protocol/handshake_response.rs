@@ -0,0 +1,67 @@
+use sqlx_core::Error;
+
+use crate::protocol::buf::Buf;
+use crate::protocol::{Capabilities, Decode};
+
+/// The response a client sends after receiving the server's initial [`Handshake`](super::Handshake).
+///
+/// Exposed so that a downstream crate implementing a MySQL-protocol-compatible server can
+/// decode what a connecting client sent and authenticate it.
+///
+/// <https://dev.mysql.com/doc/dev/mysql-server/latest/page_protocol_connection_phase_packets_protocol_handshake_response.html>
+#[derive(Debug)]
+pub struct HandshakeResponse {
+    pub capabilities: Capabilities,
+    pub max_packet_size: u32,
+    pub charset: u8,
+    pub username: String,
+    pub auth_response: Vec<u8>,
+    pub database: Option<String>,
+    pub auth_plugin_name: Option<String>,
+}
+
+impl<'de> Decode<'de> for HandshakeResponse {
+    fn decode_with(buf: &'de [u8], _: Capabilities) -> Result<Self, Error> {
+        let mut buf = Buf::new(buf);
+
+        let capabilities_lower = buf.take_u32()?;
+        let max_packet_size = buf.take_u32()?;
+        let charset = buf.take_u8()?;
+
+        // 23 bytes reserved, all zero
+        buf.take_bytes(23)?;
+
+        let capabilities = Capabilities::from_bits_truncate(u64::from(capabilities_lower));
+
+        let username = buf.take_nul_string()?;
+
+        let auth_response = if capabilities.contains(Capabilities::PLUGIN_AUTH_LENENC_DATA) {
+            buf.take_bytes_lenenc()?.unwrap_or_default().to_vec()
+        } else {
+            let len = buf.take_u8()? as usize;
+            buf.take_bytes(len)?.to_vec()
+        };
+
+        let database = if capabilities.contains(Capabilities::CONNECT_WITH_DB) {
+            Some(buf.take_nul_string()?)
+        } else {
+            None
+        };
+
+        let auth_plugin_name = if capabilities.contains(Capabilities::PLUGIN_AUTH) {
+            Some(buf.take_nul_string()?)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            capabilities,
+            max_packet_size,
+            charset,
+            username,
+            auth_response,
+            database,
+            auth_plugin_name,
+        })
+    }
+}
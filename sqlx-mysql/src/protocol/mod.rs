@@ -0,0 +1,30 @@
+//! Types and codecs for the MySQL client/server wire protocol.
+//!
+//! Most of this module is `pub(crate)`, used internally by [`MySqlConnection`](crate::MySqlConnection)
+//! to speak the protocol as a client. The pieces needed to speak it from the *server* side
+//! (to build a proxy or gateway on top of this crate) are `pub`: [`Capabilities`],
+//! [`HandshakeResponse`], the `OK`/`ERR`/`EOF` packets, and the [`auth`] module.
+
+pub mod auth;
+
+mod buf;
+mod capabilities;
+mod command;
+mod decode;
+mod encode;
+mod handshake;
+mod handshake_response;
+mod packets;
+mod session_track;
+
+pub use capabilities::Capabilities;
+pub use command::Command;
+pub use encode::Encode;
+pub use handshake_response::HandshakeResponse;
+pub use packets::{EofPacket, ErrPacket, OkPacket, StatusFlags};
+
+pub(crate) use buf::Buf;
+pub(crate) use decode::Decode;
+pub(crate) use handshake::Handshake;
+pub(crate) use packets::{ColumnDefinition, StmtPrepareOkPacket, TextRow};
+pub(crate) use session_track::{decode_session_state_changes, SessionStateChange};
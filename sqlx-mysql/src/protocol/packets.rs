@@ -0,0 +1,284 @@
+use sqlx_core::Error;
+
+use crate::protocol::buf::Buf;
+use crate::protocol::{Capabilities, Decode, Encode};
+
+// <https://dev.mysql.com/doc/dev/mysql-server/latest/page_protocol_basic_response_packets.html>
+const OK_PACKET_HEADER: u8 = 0x00;
+const EOF_PACKET_HEADER: u8 = 0xfe;
+const ERR_PACKET_HEADER: u8 = 0xff;
+
+bitflags::bitflags! {
+    /// Status flags carried by [`OkPacket`] and [`EofPacket`], describing the state of the
+    /// current session and the result set just sent.
+    #[derive(Default)]
+    pub struct StatusFlags: u16 {
+        const MORE_RESULTS_EXISTS = 1 << 3;
+        const SESSION_STATE_CHANGED = 1 << 14;
+    }
+}
+
+/// Indicates a command completed successfully.
+///
+/// <https://dev.mysql.com/doc/dev/mysql-server/latest/page_protocol_basic_ok_packet.html>
+#[derive(Debug)]
+pub struct OkPacket {
+    pub affected_rows: u64,
+    pub last_insert_id: u64,
+    pub status: StatusFlags,
+    pub warnings: u16,
+    pub info: String,
+    pub session_state_changes: Vec<u8>,
+}
+
+impl<'de> Decode<'de> for OkPacket {
+    fn decode_with(buf: &'de [u8], capabilities: Capabilities) -> Result<Self, Error> {
+        let mut buf = Buf::new(buf);
+
+        let header = buf.take_u8()?;
+        if header != OK_PACKET_HEADER && header != EOF_PACKET_HEADER {
+            return Err(Error::protocol(format!("expected OK packet, got header {header:#x}")));
+        }
+
+        let affected_rows = buf.take_int_lenenc()?;
+        let last_insert_id = buf.take_int_lenenc()?;
+
+        let status = StatusFlags::from_bits_truncate(buf.take_u16()?);
+        let warnings = buf.take_u16()?;
+
+        let info;
+        let mut session_state_changes = Vec::new();
+
+        if capabilities.contains(Capabilities::SESSION_TRACK) {
+            info = String::from_utf8_lossy(buf.take_bytes_lenenc()?.unwrap_or_default()).into_owned();
+
+            if status.contains(StatusFlags::SESSION_STATE_CHANGED) {
+                session_state_changes = buf.take_bytes_lenenc()?.unwrap_or_default().to_vec();
+            }
+        } else {
+            info = String::from_utf8_lossy(buf.take_rest()).into_owned();
+        }
+
+        Ok(Self { affected_rows, last_insert_id, status, warnings, info, session_state_changes })
+    }
+}
+
+impl Encode for OkPacket {
+    fn encode(&self, buf: &mut Vec<u8>, capabilities: Capabilities) {
+        buf.push(OK_PACKET_HEADER);
+
+        put_int_lenenc(buf, self.affected_rows);
+        put_int_lenenc(buf, self.last_insert_id);
+
+        buf.extend_from_slice(&self.status.bits().to_le_bytes());
+        buf.extend_from_slice(&self.warnings.to_le_bytes());
+
+        if capabilities.contains(Capabilities::SESSION_TRACK) {
+            put_bytes_lenenc(buf, self.info.as_bytes());
+
+            if self.status.contains(StatusFlags::SESSION_STATE_CHANGED) {
+                put_bytes_lenenc(buf, &self.session_state_changes);
+            }
+        } else {
+            buf.extend_from_slice(self.info.as_bytes());
+        }
+    }
+}
+
+/// Indicates a command failed.
+///
+/// <https://dev.mysql.com/doc/dev/mysql-server/latest/page_protocol_basic_err_packet.html>
+#[derive(Debug)]
+pub struct ErrPacket {
+    pub code: u16,
+    pub sql_state: String,
+    pub message: String,
+}
+
+impl<'de> Decode<'de> for ErrPacket {
+    fn decode_with(buf: &'de [u8], _: Capabilities) -> Result<Self, Error> {
+        let mut buf = Buf::new(buf);
+
+        let header = buf.take_u8()?;
+        if header != ERR_PACKET_HEADER {
+            return Err(Error::protocol(format!("expected ERR packet, got header {header:#x}")));
+        }
+
+        let code = buf.take_u16()?;
+
+        // marker byte for the (always-present, in the `PROTOCOL_41` era) SQL state
+        let marker = buf.take_u8()?;
+        if marker != b'#' {
+            return Err(Error::protocol("expected SQL state marker in ERR packet"));
+        }
+
+        let sql_state = String::from_utf8_lossy(buf.take_bytes(5)?).into_owned();
+        let message = String::from_utf8_lossy(buf.take_rest()).into_owned();
+
+        Ok(Self { code, sql_state, message })
+    }
+}
+
+impl Encode for ErrPacket {
+    fn encode(&self, buf: &mut Vec<u8>, _: Capabilities) {
+        buf.push(ERR_PACKET_HEADER);
+        buf.extend_from_slice(&self.code.to_le_bytes());
+        buf.push(b'#');
+        buf.extend_from_slice(self.sql_state.as_bytes());
+        buf.extend_from_slice(self.message.as_bytes());
+    }
+}
+
+/// Marks the end of a result set, on servers that have not negotiated
+/// [`Capabilities::DEPRECATE_EOF`] (in which case an [`OkPacket`] is sent instead).
+///
+/// <https://dev.mysql.com/doc/dev/mysql-server/latest/page_protocol_basic_eof_packet.html>
+#[derive(Debug)]
+pub struct EofPacket {
+    pub status: StatusFlags,
+    pub warnings: u16,
+}
+
+impl<'de> Decode<'de> for EofPacket {
+    fn decode_with(buf: &'de [u8], _: Capabilities) -> Result<Self, Error> {
+        let mut buf = Buf::new(buf);
+
+        let header = buf.take_u8()?;
+        if header != EOF_PACKET_HEADER {
+            return Err(Error::protocol(format!("expected EOF packet, got header {header:#x}")));
+        }
+
+        let warnings = buf.take_u16()?;
+        let status = StatusFlags::from_bits_truncate(buf.take_u16()?);
+
+        Ok(Self { status, warnings })
+    }
+}
+
+impl Encode for EofPacket {
+    fn encode(&self, buf: &mut Vec<u8>, _: Capabilities) {
+        buf.push(EOF_PACKET_HEADER);
+        buf.extend_from_slice(&self.warnings.to_le_bytes());
+        buf.extend_from_slice(&self.status.bits().to_le_bytes());
+    }
+}
+
+/// The response to a successful `COM_STMT_PREPARE`, ahead of the parameter and column
+/// definitions it promises.
+///
+/// <https://dev.mysql.com/doc/dev/mysql-server/latest/page_protocol_com_stmt_prepare_response.html>
+#[derive(Debug)]
+pub(crate) struct StmtPrepareOkPacket {
+    pub(crate) statement_id: u32,
+    pub(crate) num_columns: u16,
+    pub(crate) num_params: u16,
+    pub(crate) warning_count: u16,
+}
+
+impl<'de> Decode<'de> for StmtPrepareOkPacket {
+    fn decode_with(buf: &'de [u8], _: Capabilities) -> Result<Self, Error> {
+        let mut buf = Buf::new(buf);
+
+        let header = buf.take_u8()?;
+        if header != OK_PACKET_HEADER {
+            return Err(Error::protocol(format!(
+                "expected COM_STMT_PREPARE OK packet, got header {header:#x}"
+            )));
+        }
+
+        let statement_id = buf.take_u32()?;
+        let num_columns = buf.take_u16()?;
+        let num_params = buf.take_u16()?;
+
+        // filler
+        buf.take_u8()?;
+
+        let warning_count = buf.take_u16()?;
+
+        Ok(Self { statement_id, num_columns, num_params, warning_count })
+    }
+}
+
+/// Describes one column of a result set, as sent ahead of its rows.
+///
+/// <https://dev.mysql.com/doc/dev/mysql-server/latest/page_protocol_com_query_response_text_resultset_column_definition.html>
+#[derive(Debug, Clone)]
+pub(crate) struct ColumnDefinition {
+    pub(crate) name: String,
+    pub(crate) character_set: u16,
+    pub(crate) column_length: u32,
+    pub(crate) column_type: u8,
+    pub(crate) flags: u16,
+    pub(crate) decimals: u8,
+}
+
+impl<'de> Decode<'de> for ColumnDefinition {
+    fn decode_with(buf: &'de [u8], _: Capabilities) -> Result<Self, Error> {
+        let mut buf = Buf::new(buf);
+
+        // catalog, schema, table, org_table: always "def" / unused by this driver
+        buf.take_bytes_lenenc()?;
+        buf.take_bytes_lenenc()?;
+        buf.take_bytes_lenenc()?;
+        buf.take_bytes_lenenc()?;
+
+        let name = String::from_utf8_lossy(buf.take_bytes_lenenc()?.unwrap_or_default()).into_owned();
+
+        // org_name
+        buf.take_bytes_lenenc()?;
+
+        // length of the fixed-length fields below, always 0x0c
+        buf.take_int_lenenc()?;
+
+        let character_set = buf.take_u16()?;
+        let column_length = buf.take_u32()?;
+        let column_type = buf.take_u8()?;
+        let flags = buf.take_u16()?;
+        let decimals = buf.take_u8()?;
+
+        Ok(Self { name, character_set, column_length, column_type, flags, decimals })
+    }
+}
+
+/// One row of a text-protocol result set: each column is either a length-encoded string or a
+/// length-encoded `NULL`.
+///
+/// <https://dev.mysql.com/doc/dev/mysql-server/latest/page_protocol_com_query_response_text_resultset_row.html>
+#[derive(Debug)]
+pub(crate) struct TextRow(pub(crate) Vec<Option<Vec<u8>>>);
+
+impl TextRow {
+    /// Decodes a row packet given the column count already known from the result set's column
+    /// definitions, rather than relying on the packet's leading byte to tell a row apart from
+    /// the OK/EOF terminator that follows the last one.
+    pub(crate) fn decode(buf: &[u8], column_count: usize) -> Result<Self, Error> {
+        let mut buf = Buf::new(buf);
+        let mut values = Vec::with_capacity(column_count);
+
+        for _ in 0..column_count {
+            values.push(buf.take_bytes_lenenc()?.map(<[u8]>::to_vec));
+        }
+
+        Ok(Self(values))
+    }
+}
+
+fn put_int_lenenc(buf: &mut Vec<u8>, value: u64) {
+    if value < 251 {
+        buf.push(value as u8);
+    } else if value <= 0xff_ff {
+        buf.push(0xfc);
+        buf.extend_from_slice(&(value as u16).to_le_bytes());
+    } else if value <= 0xff_ff_ff {
+        buf.push(0xfd);
+        buf.extend_from_slice(&(value as u32).to_le_bytes()[..3]);
+    } else {
+        buf.push(0xfe);
+        buf.extend_from_slice(&value.to_le_bytes());
+    }
+}
+
+fn put_bytes_lenenc(buf: &mut Vec<u8>, bytes: &[u8]) {
+    put_int_lenenc(buf, bytes.len() as u64);
+    buf.extend_from_slice(bytes);
+}
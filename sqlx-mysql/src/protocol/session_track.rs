@@ -0,0 +1,133 @@
+use sqlx_core::Error;
+
+use crate::protocol::buf::Buf;
+
+// <https://dev.mysql.com/doc/dev/mysql-server/latest/page_protocol_basic_ok_packet.html#sect_protocol_basic_ok_packet_sesstrack>
+const SESSION_TRACK_SYSTEM_VARIABLES: u8 = 0x00;
+const SESSION_TRACK_SCHEMA: u8 = 0x01;
+const SESSION_TRACK_STATE_CHANGE: u8 = 0x02;
+const SESSION_TRACK_GTIDS: u8 = 0x03;
+
+/// One entry of the session-state-change payload that trails an [`OkPacket`](super::OkPacket)
+/// when its `status` has [`StatusFlags::SESSION_STATE_CHANGED`](super::StatusFlags) set.
+#[derive(Debug)]
+pub(crate) enum SessionStateChange {
+    /// A session system variable was changed, e.g. by `SET time_zone = ...` or as a
+    /// side effect of `USE db` changing `character_set_client` and friends.
+    SystemVariable { name: String, value: String },
+
+    /// The default schema (database) changed, e.g. by `USE db`.
+    Schema(String),
+
+    /// Session state tracking was turned on or off for the session.
+    StateChange(bool),
+
+    /// The GTIDs generated by the statement just executed.
+    Gtids(String),
+
+    /// A session-track type this driver does not (yet) interpret.
+    Other { kind: u8, data: Vec<u8> },
+}
+
+/// Decodes [`OkPacket::session_state_changes`](super::OkPacket)'s raw bytes into its
+/// individual entries: a sequence of `(type, length-encoded data)` pairs.
+pub(crate) fn decode_session_state_changes(raw: &[u8]) -> Result<Vec<SessionStateChange>, Error> {
+    let mut buf = Buf::new(raw);
+    let mut changes = Vec::new();
+
+    while !buf.is_empty() {
+        let kind = buf.take_u8()?;
+        let data = buf.take_bytes_lenenc()?.unwrap_or_default();
+
+        changes.push(match kind {
+            SESSION_TRACK_SYSTEM_VARIABLES => {
+                let mut data = Buf::new(data);
+                let name = take_lenenc_string(&mut data)?;
+                let value = take_lenenc_string(&mut data)?;
+
+                SessionStateChange::SystemVariable { name, value }
+            }
+
+            SESSION_TRACK_SCHEMA => SessionStateChange::Schema(take_lenenc_string(&mut Buf::new(data))?),
+
+            SESSION_TRACK_STATE_CHANGE => {
+                // the payload is the ASCII character `'0'` or `'1'`, not a raw boolean byte
+                SessionStateChange::StateChange(data.first().copied() == Some(b'1'))
+            }
+
+            SESSION_TRACK_GTIDS => SessionStateChange::Gtids(take_lenenc_string(&mut Buf::new(data))?),
+
+            kind => SessionStateChange::Other { kind, data: data.to_vec() },
+        });
+    }
+
+    Ok(changes)
+}
+
+fn take_lenenc_string(buf: &mut Buf<'_>) -> Result<String, Error> {
+    Ok(String::from_utf8_lossy(buf.take_bytes_lenenc()?.unwrap_or_default()).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // every length here is small enough to fit the single-byte length-encoded-integer form
+    fn lenenc_str(s: &str) -> Vec<u8> {
+        let mut buf = vec![s.len() as u8];
+        buf.extend_from_slice(s.as_bytes());
+        buf
+    }
+
+    fn entry(kind: u8, data: &[u8]) -> Vec<u8> {
+        let mut buf = vec![kind, data.len() as u8];
+        buf.extend_from_slice(data);
+        buf
+    }
+
+    #[test]
+    fn decodes_system_variable_change() {
+        let mut data = lenenc_str("time_zone");
+        data.extend(lenenc_str("+00:00"));
+
+        let raw = entry(SESSION_TRACK_SYSTEM_VARIABLES, &data);
+        let changes = decode_session_state_changes(&raw).unwrap();
+
+        assert!(matches!(
+            changes.as_slice(),
+            [SessionStateChange::SystemVariable { name, value }]
+                if name == "time_zone" && value == "+00:00"
+        ));
+    }
+
+    #[test]
+    fn decodes_schema_change() {
+        let raw = entry(SESSION_TRACK_SCHEMA, &lenenc_str("testdb"));
+        let changes = decode_session_state_changes(&raw).unwrap();
+
+        assert!(matches!(changes.as_slice(), [SessionStateChange::Schema(schema)] if schema == "testdb"));
+    }
+
+    #[test]
+    fn decodes_state_change_ascii_flag() {
+        let raw = entry(SESSION_TRACK_STATE_CHANGE, b"1");
+        let changes = decode_session_state_changes(&raw).unwrap();
+
+        assert!(matches!(changes.as_slice(), [SessionStateChange::StateChange(true)]));
+
+        let raw = entry(SESSION_TRACK_STATE_CHANGE, b"0");
+        let changes = decode_session_state_changes(&raw).unwrap();
+
+        assert!(matches!(changes.as_slice(), [SessionStateChange::StateChange(false)]));
+    }
+
+    #[test]
+    fn decodes_multiple_entries_in_sequence() {
+        let mut raw = entry(SESSION_TRACK_SCHEMA, &lenenc_str("testdb"));
+        raw.extend(entry(SESSION_TRACK_GTIDS, &lenenc_str("3E11FA47-71CA-11E1-9E33-C80AA9429562:1-5")));
+
+        let changes = decode_session_state_changes(&raw).unwrap();
+
+        assert!(matches!(changes.as_slice(), [SessionStateChange::Schema(_), SessionStateChange::Gtids(_)]));
+    }
+}
@@ -0,0 +1,225 @@
+//! Building blocks for implementing a MySQL-protocol-compatible *server*.
+//!
+//! [`MySqlServerConnection`] reuses [`BufStream`], [`Capabilities`], and the packet framing
+//! that [`MySqlConnection`](crate::MySqlConnection) uses as a client, but from the other end
+//! of the wire: it writes the initial [`Handshake`](crate::protocol::Handshake)-equivalent
+//! packet (generating the auth scramble), decodes the client's
+//! [`HandshakeResponse`](crate::protocol::HandshakeResponse), and lets the caller verify a
+//! `mysql_native_password` response via [`crate::protocol::auth`]. This is enough to build a
+//! protocol-compatible proxy or gateway without re-deriving the wire format.
+
+use rand::RngCore;
+
+use sqlx_core::io::BufStream;
+use sqlx_core::net::Stream as NetStream;
+use sqlx_core::Runtime;
+
+use crate::protocol::{Capabilities, Command, Decode, Encode, HandshakeResponse};
+
+const MAX_PACKET_SIZE: usize = 0xFF_FF_FF;
+
+// the default capabilities a `MySqlServerConnection` advertises to a connecting client
+fn server_capabilities() -> Capabilities {
+    Capabilities::PROTOCOL_41
+        | Capabilities::LONG_PASSWORD
+        | Capabilities::SECURE_CONNECTION
+        | Capabilities::PLUGIN_AUTH
+        | Capabilities::CONNECT_WITH_DB
+        | Capabilities::DEPRECATE_EOF
+}
+
+/// A server-side view of a single MySQL protocol connection.
+#[allow(clippy::module_name_repetitions)]
+pub struct MySqlServerConnection<Rt>
+where
+    Rt: Runtime,
+{
+    stream: BufStream<Rt, NetStream<Rt>>,
+    sequence_id: u8,
+    capabilities: Capabilities,
+}
+
+impl<Rt> MySqlServerConnection<Rt>
+where
+    Rt: Runtime,
+{
+    /// Wraps an accepted stream, ready to begin the connection phase.
+    pub fn new(stream: NetStream<Rt>) -> Self {
+        Self {
+            stream: BufStream::with_capacity(stream, 4096, 1024),
+            sequence_id: 0,
+            capabilities: server_capabilities(),
+        }
+    }
+
+    /// The capabilities negotiated with the client, valid after
+    /// [`Self::read_handshake_response_async`] returns.
+    pub fn capabilities(&self) -> Capabilities {
+        self.capabilities
+    }
+
+    #[cfg(feature = "async")]
+    pub async fn send_handshake_async(
+        &mut self,
+        connection_id: u32,
+        server_version: &str,
+    ) -> sqlx_core::Result<[u8; 20]>
+    where
+        Rt: sqlx_core::Async,
+    {
+        let scramble = generate_scramble();
+        let payload = encode_initial_handshake(connection_id, server_version, &scramble);
+
+        self.write_raw_packet_async(&payload).await?;
+
+        Ok(scramble)
+    }
+
+    #[cfg(feature = "async")]
+    pub async fn read_handshake_response_async(&mut self) -> sqlx_core::Result<HandshakeResponse>
+    where
+        Rt: sqlx_core::Async,
+    {
+        let payload = self.read_packet_async().await?;
+        let response = HandshakeResponse::decode(&payload)?;
+
+        self.capabilities &= response.capabilities;
+
+        Ok(response)
+    }
+
+    #[cfg(feature = "async")]
+    pub async fn read_command_async(&mut self) -> sqlx_core::Result<Command>
+    where
+        Rt: sqlx_core::Async,
+    {
+        self.sequence_id = 0;
+
+        let payload = self.read_packet_async().await?;
+        Command::decode(&payload)
+    }
+
+    #[cfg(feature = "async")]
+    pub async fn write_packet_async(&mut self, payload: impl Encode) -> sqlx_core::Result<()>
+    where
+        Rt: sqlx_core::Async,
+    {
+        let mut buf = Vec::new();
+        payload.encode(&mut buf, self.capabilities);
+
+        self.write_raw_packet_async(&buf).await
+    }
+
+    #[cfg(feature = "async")]
+    async fn write_raw_packet_async(&mut self, payload: &[u8]) -> sqlx_core::Result<()>
+    where
+        Rt: sqlx_core::Async,
+    {
+        let mut chunks = payload.chunks(MAX_PACKET_SIZE).peekable();
+
+        if chunks.peek().is_none() {
+            self.write_chunk_async(&[]).await?;
+        }
+
+        while let Some(chunk) = chunks.next() {
+            self.write_chunk_async(chunk).await?;
+
+            if chunk.len() == MAX_PACKET_SIZE && chunks.peek().is_none() {
+                self.write_chunk_async(&[]).await?;
+            }
+        }
+
+        self.stream.flush().await?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "async")]
+    async fn write_chunk_async(&mut self, chunk: &[u8]) -> sqlx_core::Result<()>
+    where
+        Rt: sqlx_core::Async,
+    {
+        let len = (chunk.len() as u32).to_le_bytes();
+
+        self.stream.write(&len[..3]).await?;
+        self.stream.write(&[self.sequence_id]).await?;
+        self.stream.write(chunk).await?;
+
+        self.sequence_id = self.sequence_id.wrapping_add(1);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "async")]
+    async fn read_packet_async(&mut self) -> sqlx_core::Result<Vec<u8>>
+    where
+        Rt: sqlx_core::Async,
+    {
+        let mut payload = Vec::new();
+
+        loop {
+            let mut header = [0_u8; 4];
+            self.stream.read_exact(&mut header).await?;
+
+            let len = u32::from_le_bytes([header[0], header[1], header[2], 0]) as usize;
+            self.sequence_id = header[3].wrapping_add(1);
+
+            let mut chunk = vec![0_u8; len];
+            self.stream.read_exact(&mut chunk).await?;
+            payload.extend_from_slice(&chunk);
+
+            if len < MAX_PACKET_SIZE {
+                break;
+            }
+        }
+
+        Ok(payload)
+    }
+}
+
+fn generate_scramble() -> [u8; 20] {
+    let mut scramble = [0_u8; 20];
+    rand::thread_rng().fill_bytes(&mut scramble);
+
+    // the scramble is sent to the client as a NUL-terminated string split across two fields;
+    // it must not itself contain a NUL byte
+    for byte in &mut scramble {
+        if *byte == 0 {
+            *byte = 1;
+        }
+    }
+
+    scramble
+}
+
+// the server's half of the initial handshake packet: protocol version 10, server version,
+// connection id, and the auth plugin data (scramble) split into its 8-byte and 12-byte
+// halves around the capability/charset/status fields, as `Handshake::decode` expects
+fn encode_initial_handshake(connection_id: u32, server_version: &str, scramble: &[u8; 20]) -> Vec<u8> {
+    let mut buf = Vec::new();
+
+    buf.push(10); // protocol_version
+    buf.extend_from_slice(server_version.as_bytes());
+    buf.push(0);
+    buf.extend_from_slice(&connection_id.to_le_bytes());
+    buf.extend_from_slice(&scramble[..8]);
+    buf.push(0); // filler
+
+    let capabilities = server_capabilities().bits();
+    buf.extend_from_slice(&(capabilities as u16).to_le_bytes());
+
+    buf.push(45); // utf8mb4_general_ci
+    buf.extend_from_slice(&0_u16.to_le_bytes()); // status flags
+    buf.extend_from_slice(&((capabilities >> 16) as u16).to_le_bytes());
+
+    buf.push(21); // auth_plugin_data_len: 8 + 12 + 1
+    buf.extend_from_slice(&[0_u8; 10]); // reserved
+
+    buf.extend_from_slice(&scramble[8..]);
+    buf.push(0);
+
+    buf.extend_from_slice(b"mysql_native_password");
+    buf.push(0);
+
+    buf
+}
@@ -0,0 +1,23 @@
+//! The stream type underneath a [`MySqlConnection`](crate::MySqlConnection): a native
+//! TCP/Unix socket (optionally upgraded to TLS) when the `native` feature is enabled, or an
+//! embedder-injected byte channel when it is not.
+//!
+//! `native` is on by default. Turning it off drops the dependency on `std::net` and the TLS
+//! backend entirely, which is what lets this crate build for `wasm32-unknown-unknown`: a WASM
+//! sandbox has no sockets of its own, so the host environment (a `fetch`-based proxy, a
+//! `WebSocket`, a Worker message port, ...) dials out on the driver's behalf and hands it a
+//! [`WasmStream`] wrapping whatever duplex channel it opened.
+
+#[cfg(not(feature = "native"))]
+mod wasm;
+
+#[cfg(not(feature = "native"))]
+pub use wasm::WasmStream;
+
+/// The concrete stream type [`MySqlConnection`](crate::MySqlConnection)'s [`BufStream`
+/// ](sqlx_core::io::BufStream) is built on, selected by the `native` feature.
+#[cfg(feature = "native")]
+pub(crate) type MySqlStream<Rt> = sqlx_core::net::Stream<Rt>;
+
+#[cfg(not(feature = "native"))]
+pub(crate) type MySqlStream<Rt> = wasm::RuntimeWasmStream<Rt>;
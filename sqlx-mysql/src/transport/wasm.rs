@@ -0,0 +1,105 @@
+use std::fmt::{self, Debug, Formatter};
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_util::{AsyncRead, AsyncWrite};
+
+// a single trait object combining both halves of the duplex channel, so `WasmStream` itself
+// stays free of a type parameter for the embedder's channel type -- `MySqlConnection<Rt>`
+// only ever has the one generic, `Rt`, on every target
+trait DuplexChannel: AsyncRead + AsyncWrite + Send {}
+
+impl<T: AsyncRead + AsyncWrite + Send> DuplexChannel for T {}
+
+/// A duplex byte channel supplied by the host environment, used as
+/// [`MySqlConnection`](crate::MySqlConnection)'s transport when this crate is built without
+/// the `native` feature.
+///
+/// There is no `std::net` to dial from inside a `wasm32-unknown-unknown` sandbox, so the
+/// embedder opens the connection to the server itself -- over a `fetch`-based proxy, a
+/// `WebSocket`, a Worker message port, or whatever else the host makes available -- and wraps
+/// it in one of these instead of the driver being able to connect from a host/port pair.
+///
+/// Pass one to [`MySqlConnection::connect_with_stream_async`
+/// ](crate::MySqlConnection::connect_with_stream_async) in place of
+/// [`MySqlConnection::connect_async`](crate::MySqlConnection::connect_async), which requires
+/// the `native` feature.
+pub struct WasmStream {
+    channel: Pin<Box<dyn DuplexChannel>>,
+}
+
+impl WasmStream {
+    /// Wraps an embedder-supplied duplex byte channel for use as a
+    /// [`MySqlConnection`](crate::MySqlConnection)'s transport.
+    pub fn new(channel: impl AsyncRead + AsyncWrite + Send + 'static) -> Self {
+        Self { channel: Box::pin(channel) }
+    }
+}
+
+impl Debug for WasmStream {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WasmStream").finish()
+    }
+}
+
+impl AsyncRead for WasmStream {
+    fn poll_read(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<std::io::Result<usize>> {
+        self.channel.as_mut().poll_read(cx, buf)
+    }
+}
+
+impl AsyncWrite for WasmStream {
+    fn poll_write(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<std::io::Result<usize>> {
+        self.channel.as_mut().poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        self.channel.as_mut().poll_flush(cx)
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        self.channel.as_mut().poll_close(cx)
+    }
+}
+
+/// [`WasmStream`] carrying the zero-sized runtime marker [`crate::transport::MySqlStream`] is
+/// parameterized by on every target, so the alias stays generic over `Rt` here the same way it
+/// is under the `native` feature even though the embedder-supplied channel itself never depends
+/// on which [`Runtime`](sqlx_core::Runtime) is in use.
+pub(crate) struct RuntimeWasmStream<Rt> {
+    inner: WasmStream,
+    runtime: PhantomData<Rt>,
+}
+
+impl<Rt> RuntimeWasmStream<Rt> {
+    pub(crate) fn new(inner: WasmStream) -> Self {
+        Self { inner, runtime: PhantomData }
+    }
+}
+
+impl<Rt> Debug for RuntimeWasmStream<Rt> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl<Rt> AsyncRead for RuntimeWasmStream<Rt> {
+    fn poll_read(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_read(cx, buf)
+    }
+}
+
+impl<Rt> AsyncWrite for RuntimeWasmStream<Rt> {
+    fn poll_write(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.inner).poll_close(cx)
+    }
+}